@@ -0,0 +1,110 @@
+// 缩写（PHP Markdown Extra 风格）
+//
+// *[HTML]: Hyper Text Markup Language
+//
+// 定义行在解析前从源文本中移除（围栏代码块内的保持原样），文本中与缩写完全相同的整词
+// 由 `TextPass` 替换为 `<abbr title="Hyper Text Markup Language">HTML</abbr>`。
+// 规则：
+// - 定义行最多 3 个空格缩进，缩写区分大小写，可以包含空格；同一缩写多次定义时后面的生效
+// - 说明可以为空，此时输出不带 title 的 `<abbr>`
+// - 缩写前后不能紧贴字母、数字或 `_`；同一位置有多个缩写时优先匹配较长的
+
+use std::ops::Range;
+
+use crate::container::source_lines;
+use crate::escape_html;
+
+/// 文档中定义的一个缩写
+pub(crate) struct Abbreviation {
+    pub(crate) label: String,
+    pub(crate) title: String,
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+// `*[ABBR]: 说明` 定义行：(缩写, 说明)
+fn definition(line: &str) -> Option<(&str, &str)> {
+    let trimmed = line.trim_start_matches(' ');
+    if line.len() - trimmed.len() > 3 {
+        return None;
+    }
+    let rest = trimmed.strip_prefix("*[")?;
+    let (label, title) = rest.split_once("]:")?;
+    let label = label.trim();
+    if label.is_empty() || label.contains(['[', ']']) {
+        return None;
+    }
+    Some((label, title.trim()))
+}
+
+/// 收集并移除缩写定义行；没有定义时返回 `None`
+pub(crate) fn extract_abbreviations(source: &str) -> Option<(String, Vec<Abbreviation>)> {
+    if !source.contains("*[") {
+        return None;
+    }
+
+    let mut output = String::with_capacity(source.len());
+    let mut abbreviations: Vec<Abbreviation> = Vec::new();
+
+    for (line, in_code) in source_lines(source) {
+        match definition(line).filter(|_| !in_code) {
+            Some((label, title)) => {
+                abbreviations.retain(|abbreviation| abbreviation.label != label);
+                abbreviations.push(Abbreviation {
+                    label: label.to_string(),
+                    title: title.to_string(),
+                });
+            }
+            None => output.push_str(line),
+        }
+    }
+
+    if abbreviations.is_empty() {
+        return None;
+    }
+    // 较长的缩写优先（`HTML5` 先于 `HTML`）
+    abbreviations.sort_by_key(|abbreviation| std::cmp::Reverse(abbreviation.label.len()));
+    Some((output, abbreviations))
+}
+
+/// 查找文本中的缩写，返回 (范围, `<abbr>` HTML)
+pub(crate) fn find(text: &str, abbreviations: &[Abbreviation]) -> Vec<(Range<usize>, String)> {
+    let mut found = Vec::new();
+    if abbreviations.is_empty() {
+        return found;
+    }
+
+    let mut prev = None;
+    let mut skip_to = 0;
+    for (i, c) in text.char_indices() {
+        if i < skip_to || prev.is_some_and(is_word_char) {
+            prev = Some(c);
+            continue;
+        }
+        prev = Some(c);
+
+        let matched = abbreviations.iter().find(|abbreviation| {
+            text[i..].starts_with(&abbreviation.label)
+                && !text[i + abbreviation.label.len()..].starts_with(is_word_char)
+        });
+        let Some(abbreviation) = matched else {
+            continue;
+        };
+
+        let end = i + abbreviation.label.len();
+        let html = if abbreviation.title.is_empty() {
+            format!("<abbr>{}</abbr>", escape_html(&abbreviation.label))
+        } else {
+            format!(
+                "<abbr title=\"{}\">{}</abbr>",
+                escape_html(&abbreviation.title),
+                escape_html(&abbreviation.label)
+            )
+        };
+        found.push((i..end, html));
+        skip_to = end;
+    }
+    found
+}
@@ -0,0 +1,300 @@
+// ANSI 转义序列渲染：把终端输出中的 SGR 序列转换为 <span>
+//
+// 支持：
+// - 标准 16 色（30-37 / 90-97 前景，40-47 / 100-107 背景）→ CSS 类名
+// - 256 色（38;5;n / 48;5;n）→ 0-15 使用类名，其余使用内联 style
+// - 真彩色（38;2;r;g;b / 48;2;r;g;b）→ 内联 style
+// - 粗体、暗淡、斜体、下划线、反色、删除线
+//
+// 除了真正的 ESC 字符 (0x1B)，也识别日志里常见的文本写法：
+// `\x1b[`、`\033[`、`\e[`、`\u001b[`
+
+use crate::escape_html;
+
+const COLOR_NAMES: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+// 文本形式的 ESC 写法（按长度降序，避免前缀误判）
+const TEXTUAL_ESCAPES: [&str; 4] = ["\\u001b[", "\\x1b[", "\\033[", "\\e["];
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Color {
+    // 0-15：标准色与高亮色
+    Indexed(u8),
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    // 256 色调色板中 16 以上的颜色没有对应的类名，转换为 RGB
+    fn from_palette(n: u8) -> Color {
+        match n {
+            0..=15 => Color::Indexed(n),
+            16..=231 => {
+                let n = n - 16;
+                let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+                Color::Rgb(level(n / 36), level((n / 6) % 6), level(n % 6))
+            }
+            _ => {
+                let gray = 8 + (n - 232) * 10;
+                Color::Rgb(gray, gray, gray)
+            }
+        }
+    }
+
+    fn class_name(self, prefix: &str) -> Option<String> {
+        match self {
+            Color::Indexed(n) if n < 8 => Some(format!("{}{}", prefix, COLOR_NAMES[n as usize])),
+            Color::Indexed(n) => Some(format!(
+                "{}bright-{}",
+                prefix,
+                COLOR_NAMES[(n - 8) as usize]
+            )),
+            Color::Rgb(..) => None,
+        }
+    }
+
+    fn css_value(self) -> Option<String> {
+        match self {
+            Color::Rgb(r, g, b) => Some(format!("#{:02x}{:02x}{:02x}", r, g, b)),
+            Color::Indexed(_) => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+struct Style {
+    fg: Option<Color>,
+    bg: Option<Color>,
+    bold: bool,
+    dim: bool,
+    italic: bool,
+    underline: bool,
+    inverse: bool,
+    strikethrough: bool,
+}
+
+impl Style {
+    fn apply_sgr(&mut self, params: &[u16]) {
+        // ESC[m 等价于 ESC[0m
+        if params.is_empty() {
+            *self = Style::default();
+            return;
+        }
+
+        let mut i = 0;
+        while i < params.len() {
+            match params[i] {
+                0 => *self = Style::default(),
+                1 => self.bold = true,
+                2 => self.dim = true,
+                3 => self.italic = true,
+                4 => self.underline = true,
+                7 => self.inverse = true,
+                9 => self.strikethrough = true,
+                21 | 22 => {
+                    self.bold = false;
+                    self.dim = false;
+                }
+                23 => self.italic = false,
+                24 => self.underline = false,
+                27 => self.inverse = false,
+                29 => self.strikethrough = false,
+                n @ 30..=37 => self.fg = Some(Color::Indexed((n - 30) as u8)),
+                39 => self.fg = None,
+                n @ 40..=47 => self.bg = Some(Color::Indexed((n - 40) as u8)),
+                49 => self.bg = None,
+                n @ 90..=97 => self.fg = Some(Color::Indexed((n - 90 + 8) as u8)),
+                n @ 100..=107 => self.bg = Some(Color::Indexed((n - 100 + 8) as u8)),
+                n @ (38 | 48) => {
+                    let (color, consumed) = parse_extended_color(&params[i + 1..]);
+                    if let Some(color) = color {
+                        if n == 38 {
+                            self.fg = Some(color);
+                        } else {
+                            self.bg = Some(color);
+                        }
+                    }
+                    i += consumed;
+                }
+                _ => {} // 未支持的 SGR 参数直接忽略
+            }
+            i += 1;
+        }
+    }
+
+    fn open_tag(&self) -> Option<String> {
+        if *self == Style::default() {
+            return None;
+        }
+
+        let (fg, bg) = if self.inverse {
+            (self.bg, self.fg)
+        } else {
+            (self.fg, self.bg)
+        };
+
+        let mut classes = Vec::new();
+        let mut styles = Vec::new();
+
+        if let Some(color) = fg {
+            match color.class_name("ansi-") {
+                Some(class) => classes.push(class),
+                None => styles.push(format!("color:{}", color.css_value().unwrap())),
+            }
+        }
+        if let Some(color) = bg {
+            match color.class_name("ansi-bg-") {
+                Some(class) => classes.push(class),
+                None => styles.push(format!("background-color:{}", color.css_value().unwrap())),
+            }
+        }
+        if self.inverse {
+            classes.push("ansi-inverse".to_string());
+        }
+        if self.bold {
+            classes.push("ansi-bold".to_string());
+        }
+        if self.dim {
+            classes.push("ansi-dim".to_string());
+        }
+        if self.italic {
+            classes.push("ansi-italic".to_string());
+        }
+        if self.underline {
+            classes.push("ansi-underline".to_string());
+        }
+        if self.strikethrough {
+            classes.push("ansi-strikethrough".to_string());
+        }
+
+        let mut tag = String::from("<span");
+        if !classes.is_empty() {
+            tag.push_str(&format!(r#" class="{}""#, classes.join(" ")));
+        }
+        if !styles.is_empty() {
+            tag.push_str(&format!(r#" style="{}""#, styles.join(";")));
+        }
+        tag.push('>');
+        Some(tag)
+    }
+}
+
+// 解析 38/48 之后的扩展颜色参数，返回 (颜色, 额外消耗的参数个数)
+fn parse_extended_color(rest: &[u16]) -> (Option<Color>, usize) {
+    match rest {
+        [5, n, ..] => (Some(Color::from_palette((*n).min(255) as u8)), 2),
+        [2, r, g, b, ..] => (
+            Some(Color::Rgb(
+                (*r).min(255) as u8,
+                (*g).min(255) as u8,
+                (*b).min(255) as u8,
+            )),
+            4,
+        ),
+        _ => (None, rest.len()),
+    }
+}
+
+// 在 `input` 开头识别 CSI 序列的引导符，返回引导符长度
+fn csi_introducer_len(input: &str) -> Option<usize> {
+    if input.starts_with("\x1b[") {
+        return Some(2);
+    }
+    TEXTUAL_ESCAPES
+        .iter()
+        .find(|prefix| input.starts_with(*prefix))
+        .map(|prefix| prefix.len())
+}
+
+// 在 `input` 开头识别完整的 CSI 序列，返回 (参数字节, 终止字节, 序列总长度)
+fn csi_sequence(input: &str) -> Option<(&str, u8, usize)> {
+    let intro_len = csi_introducer_len(input)?;
+    // CSI 序列：参数字节 0x30-0x3F，中间字节 0x20-0x2F，终止字节 0x40-0x7E
+    let body = &input[intro_len..];
+    let body_len = body
+        .bytes()
+        .position(|b| !(0x20..=0x3f).contains(&b))
+        .unwrap_or(body.len());
+    match body.as_bytes().get(body_len) {
+        Some(&final_byte @ 0x40..=0x7e) => {
+            Some((&body[..body_len], final_byte, intro_len + body_len + 1))
+        }
+        // 不完整的序列按普通文本处理
+        _ => None,
+    }
+}
+
+/// 去掉文本中的 CSI 序列，得到终端上显示的纯文本（用于复制按钮的 `data-code`）
+pub(crate) fn strip_ansi(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut text_start = 0;
+    let mut pos = 0;
+    while pos < input.len() {
+        match csi_sequence(&input[pos..]) {
+            Some((_, _, len)) => {
+                output.push_str(&input[text_start..pos]);
+                pos += len;
+                text_start = pos;
+            }
+            None => pos += input[pos..].chars().next().map(char::len_utf8).unwrap_or(1),
+        }
+    }
+    output.push_str(&input[text_start..]);
+    output
+}
+
+/// 判断文本中是否包含 ANSI 转义序列（真实 ESC 或常见的文本写法）
+pub(crate) fn contains_ansi(input: &str) -> bool {
+    input.contains('\x1b') || TEXTUAL_ESCAPES.iter().any(|prefix| input.contains(prefix))
+}
+
+/// 将包含 ANSI 转义序列的文本渲染为 HTML（已转义，不含外层 <pre>）
+pub(crate) fn render_ansi(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut style = Style::default();
+    let mut span_open = false;
+    let mut text_start = 0;
+    let mut pos = 0;
+
+    while pos < input.len() {
+        let rest = &input[pos..];
+        let Some((params, final_byte, len)) = csi_sequence(rest) else {
+            pos += rest.chars().next().map(char::len_utf8).unwrap_or(1);
+            continue;
+        };
+
+        output.push_str(&escape_html(&input[text_start..pos]));
+
+        // 只处理 SGR (`m`)，其他 CSI 序列（光标移动、清屏等）直接丢弃
+        if final_byte == b'm' {
+            let params: Vec<u16> = params
+                .split([';', ':'])
+                .filter(|p| !p.is_empty())
+                .map(|p| p.parse().unwrap_or(0))
+                .collect();
+            let previous = style;
+            style.apply_sgr(&params);
+
+            if style != previous {
+                if span_open {
+                    output.push_str("</span>");
+                    span_open = false;
+                }
+                if let Some(tag) = style.open_tag() {
+                    output.push_str(&tag);
+                    span_open = true;
+                }
+            }
+        }
+
+        pos += len;
+        text_start = pos;
+    }
+
+    output.push_str(&escape_html(&input[text_start..]));
+    if span_open {
+        output.push_str("</span>");
+    }
+    output
+}
@@ -0,0 +1,180 @@
+// GFM 自动链接扩展（autolink literals）
+//
+// 文本中的 `www.`、`http://` / `https://` / `ftp://`、邮箱地址以及 `mailto:` / `xmpp:` 转为链接，
+// 规则与 GFM 规范（cmark-gfm）相同：
+// - 链接只能出现在行首、空白或 `*` `_` `~` `(` 之后（邮箱地址为本地部分的开头）
+// - 域名由字母数字、`-`、`_` 组成并以 `.` 分隔，最后两段不能包含 `_`；`www.` 链接至少包含一个 `.`
+// - 链接到空白或 `<` 为止，之后去掉结尾的标点：
+//   - `?` `!` `.` `,` `:` `*` `_` `~` `'` `"` 不属于链接
+//   - 结尾的 `)` 多于整个链接中的 `(` 时，逐个去掉多余的 `)`
+//   - 结尾形如 `&hl;` 的实体引用（或单独的 `;`）不属于链接
+// - 邮箱地址：本地部分为字母数字与 `.` `-` `_` `+`，域名至少包含一个 `.` 且不以 `-` / `_` 结尾
+
+use std::ops::Range;
+
+/// 文本中识别出的一个自动链接
+pub(crate) struct Autolink {
+    /// 链接文字在文本中的范围
+    pub(crate) range: Range<usize>,
+    pub(crate) href: String,
+}
+
+fn is_boundary(prev: Option<char>) -> bool {
+    prev.is_none_or(|c| c.is_whitespace() || matches!(c, '*' | '_' | '~' | '('))
+}
+
+fn is_email_local_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_' | '+')
+}
+
+// 链接开头的域名是否有效
+fn valid_domain(link: &str, require_period: bool) -> bool {
+    let end = link
+        .find(|c: char| !(c.is_alphanumeric() || matches!(c, '-' | '_' | '.')))
+        .unwrap_or(link.len());
+    let domain = link[..end].trim_end_matches('.');
+    if domain.is_empty() || (require_period && !domain.contains('.')) {
+        return false;
+    }
+    !domain
+        .rsplit('.')
+        .take(2)
+        .any(|segment| segment.contains('_'))
+}
+
+// 去掉结尾不属于链接的字符，返回链接的新长度
+fn trim_trailing(link: &str) -> usize {
+    let mut end = link.len();
+    while let Some(c) = link[..end].chars().next_back() {
+        match c {
+            '?' | '!' | '.' | ',' | ':' | '*' | '_' | '~' | '\'' | '"' => end -= 1,
+            ';' => {
+                let name = link[..end - 1].trim_end_matches(|c: char| c.is_ascii_alphanumeric());
+                end = match name.strip_suffix('&') {
+                    Some(before) if name.len() < end - 1 => before.len(),
+                    _ => end - 1,
+                };
+            }
+            ')' => {
+                let opening = link[..end].matches('(').count();
+                let closing = link[..end].matches(')').count();
+                if closing <= opening {
+                    break;
+                }
+                end -= 1;
+            }
+            _ => break,
+        }
+    }
+    end
+}
+
+// 从 `start` 开始匹配 www / 协议链接：(链接结尾, href)
+fn match_url(text: &str, start: usize) -> Option<(usize, String)> {
+    let rest = &text[start..];
+    let (scheme_len, prefix) = if rest.starts_with("www.") {
+        (0, "http://")
+    } else {
+        let scheme = ["http://", "https://", "ftp://"]
+            .into_iter()
+            .find(|scheme| rest.starts_with(scheme))?;
+        (scheme.len(), "")
+    };
+
+    let len = rest
+        .find(|c: char| c.is_whitespace() || c == '<')
+        .unwrap_or(rest.len());
+    let link = &rest[..trim_trailing(&rest[..len])];
+    if !valid_domain(&link[scheme_len..], scheme_len == 0) {
+        return None;
+    }
+    Some((start + link.len(), format!("{}{}", prefix, link)))
+}
+
+// 从 `start` 开始匹配邮箱地址，返回结尾位置
+fn match_email(text: &str, start: usize) -> Option<usize> {
+    let rest = &text[start..];
+    let local = rest.find(|c| !is_email_local_char(c)).unwrap_or(rest.len());
+    if local == 0 || !rest[local..].starts_with('@') {
+        return None;
+    }
+
+    let domain_start = local + 1;
+    let domain_len = rest[domain_start..]
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_')))
+        .unwrap_or(rest.len() - domain_start);
+    let domain = rest[domain_start..domain_start + domain_len].trim_end_matches('.');
+    if !domain.contains('.') || domain.starts_with('.') || domain.ends_with(['-', '_']) {
+        return None;
+    }
+    Some(start + domain_start + domain.len())
+}
+
+// `mailto:` / `xmpp:` 后接邮箱地址；xmpp 还可以带 `/资源`（字母数字、`@`、`.`）
+fn match_protocol_email(text: &str, start: usize) -> Option<usize> {
+    let rest = &text[start..];
+    let (protocol, xmpp) = if rest.starts_with("mailto:") {
+        ("mailto:", false)
+    } else if rest.starts_with("xmpp:") {
+        ("xmpp:", true)
+    } else {
+        return None;
+    };
+
+    let end = match_email(text, start + protocol.len())?;
+    if !xmpp || !text[end..].starts_with('/') {
+        return Some(end);
+    }
+    let resource = &text[end + 1..];
+    let len = resource
+        .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '@' | '.')))
+        .unwrap_or(resource.len());
+    let resource = resource[..len].trim_end_matches('.');
+    if resource.is_empty() {
+        Some(end)
+    } else {
+        Some(end + 1 + resource.len())
+    }
+}
+
+/// 查找文本中的自动链接，按出现顺序返回
+pub(crate) fn find(text: &str) -> Vec<Autolink> {
+    let mut links = Vec::new();
+    if !text.contains("www.") && !text.contains("://") && !text.contains('@') {
+        return links;
+    }
+
+    let mut prev = None;
+    let mut skip_to = 0;
+    for (i, c) in text.char_indices() {
+        if i < skip_to {
+            prev = Some(c);
+            continue;
+        }
+
+        let link = if is_boundary(prev) {
+            match_url(text, i).or_else(|| {
+                match_protocol_email(text, i).map(|end| (end, text[i..end].to_string()))
+            })
+        } else {
+            None
+        }
+        .or_else(|| {
+            // 邮箱地址从本地部分的开头匹配
+            if prev.is_some_and(is_email_local_char) {
+                return None;
+            }
+            match_email(text, i).map(|end| (end, format!("mailto:{}", &text[i..end])))
+        });
+
+        if let Some((end, href)) = link {
+            links.push(Autolink {
+                range: i..end,
+                href,
+            });
+            skip_to = end;
+        }
+        prev = Some(c);
+    }
+    links
+}
@@ -0,0 +1,192 @@
+// 代码块渲染：info string 解析、语法高亮与外层容器
+
+use syntect::html::{ClassStyle, ClassedHTMLGenerator};
+use syntect::util::LinesWithEndings;
+
+use crate::options::RenderOptions;
+use crate::{SYNTAX_SET, ansi, code_fold, escape_html, shell};
+
+// 代码块 info string 解析结果
+// 例如 ```rust title="main.rs" collapse
+// - lang: "rust"
+// - attrs: [("title", Some("main.rs")), ("collapse", None)]
+#[derive(Debug, Default)]
+pub(crate) struct CodeBlockInfo {
+    pub(crate) lang: String,
+    attrs: Vec<(String, Option<String>)>,
+}
+
+impl CodeBlockInfo {
+    pub(crate) fn parse(info: &str) -> Self {
+        let mut tokens = tokenize(info).into_iter();
+        let lang = tokens.next().unwrap_or_default();
+
+        let attrs = tokens
+            .map(|token| match token.split_once('=') {
+                Some((key, value)) => (key.to_string(), Some(unquote(value).to_string())),
+                None => (token, None),
+            })
+            .collect();
+
+        CodeBlockInfo { lang, attrs }
+    }
+
+    /// 是否包含某个属性（`key` 或 `key=value` 形式均可）
+    pub(crate) fn has(&self, key: &str) -> bool {
+        self.attrs.iter().any(|(k, _)| k == key)
+    }
+
+    /// 取 `key=value` 形式属性的值
+    pub(crate) fn attr(&self, key: &str) -> Option<&str> {
+        self.attrs
+            .iter()
+            .find(|(k, _)| k == key)
+            .and_then(|(_, v)| v.as_deref())
+    }
+}
+
+// 按空白切分 info string，引号内的空白不切分
+fn tokenize(info: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut quote = None;
+
+    for c in info.chars() {
+        match quote {
+            Some(q) if c == q => {
+                quote = None;
+                current.push(c);
+            }
+            Some(_) => current.push(c),
+            None if c == '"' || c == '\'' => {
+                quote = Some(c);
+                current.push(c);
+            }
+            None if c.is_whitespace() => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            None => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
+
+fn unquote(value: &str) -> &str {
+    for q in ['"', '\''] {
+        if let Some(inner) = value.strip_prefix(q).and_then(|v| v.strip_suffix(q)) {
+            return inner;
+        }
+    }
+    value
+}
+
+// 生成 <code> 内部的 HTML
+fn highlight(lang: &str, code: &str) -> String {
+    // 终端输出：解析 ANSI 转义序列
+    if lang == "ansi" {
+        return ansi::render_ansi(code);
+    }
+
+    // Shell 会话：区分提示符、命令与输出
+    if matches!(lang, "console" | "shell-session" | "sh-session") {
+        return shell::render_shell_session(code);
+    }
+
+    let ss = &SYNTAX_SET;
+    // 语言标识符映射：将一些常见的别名映射到正确的语法
+    let lang_normalized = match lang {
+        "jsx" => "tsx", // JSX 使用 TSX 语法高亮
+        _ => lang,
+    };
+    let syntax = ss
+        .find_syntax_by_token(lang_normalized)
+        .unwrap_or_else(|| ss.find_syntax_plain_text());
+
+    let mut html_generator =
+        ClassedHTMLGenerator::new_with_class_style(syntax, ss, ClassStyle::Spaced);
+
+    for line in LinesWithEndings::from(code) {
+        let _ = html_generator.parse_html_for_line_which_includes_newline(line);
+    }
+
+    html_generator.finalize()
+}
+
+pub(crate) fn render_code_block(
+    info: &CodeBlockInfo,
+    code: &str,
+    options: &RenderOptions,
+) -> String {
+    let lang = if info.lang.is_empty() {
+        "text"
+    } else {
+        info.lang.as_str()
+    };
+    let lang_escaped = escape_html(lang);
+
+    let highlighted = highlight(lang, code);
+    let mut pre = if code_fold::has_folds(info) {
+        let code_open = format!(r#"<pre><code class="language-{}">"#, lang_escaped);
+        code_fold::apply_folds(info, &highlighted, &code_open)
+    } else {
+        format!(
+            r#"<pre><code class="language-{}">{}</code></pre>"#,
+            lang_escaped, highlighted
+        )
+    };
+
+    // collapse：整个代码块默认折叠
+    if info.has("collapse") {
+        let line_count = code.lines().count();
+        let summary = match info.attr("title") {
+            Some(title) => escape_html(title),
+            None => format!(
+                "{} ({} line{})",
+                lang_escaped,
+                line_count,
+                if line_count == 1 { "" } else { "s" }
+            ),
+        };
+        pre = format!(
+            r#"<details class="code-collapse"><summary>{}</summary>{}</details>"#,
+            summary, pre
+        );
+    }
+
+    if !options.code_block_container {
+        return pre;
+    }
+
+    // 头部：语言标签 + 可选标题
+    let mut header = format!(
+        r#"<div class="code-block-header"><span class="code-block-lang">{}</span>"#,
+        lang_escaped
+    );
+    if let Some(title) = info.attr("title") {
+        header.push_str(&format!(
+            r#"<span class="code-block-title">{}</span>"#,
+            escape_html(title)
+        ));
+    }
+    header.push_str("</div>");
+
+    // 终端输出按 ANSI 渲染，复制的文本不应包含转义序列
+    let copy_text = if matches!(lang, "ansi" | "console" | "shell-session" | "sh-session") {
+        ansi::strip_ansi(code)
+    } else {
+        code.to_string()
+    };
+
+    format!(
+        r#"<div class="awsm-code-block" data-lang="{}" data-code="{}">{}{}</div>"#,
+        lang_escaped,
+        escape_html(&copy_text),
+        header,
+        pre
+    )
+}
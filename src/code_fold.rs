@@ -0,0 +1,174 @@
+// 代码折叠：按行重组高亮后的 HTML
+//
+// info string 属性：
+// - `maxLines=30`      只显示前 30 行，其余行放入可展开区域
+// - `fold=10-40,60-80` 把指定行区间（从 1 开始，闭区间）放入可展开区域
+//
+// 高亮器输出的 <span> 可能跨越多行，直接在行边界切开会导致标签不平衡，
+// 所以先把 HTML 拆分为独立的行：每行开头重新打开上一行未闭合的标签，行尾全部闭合。
+// `<details>` 不能放在 `<pre><code>` 内，折叠区域前后会闭合并重新打开 `<pre><code>`。
+
+use crate::code_block::CodeBlockInfo;
+
+// 一个折叠区域（行号从 1 开始，闭区间）
+struct Fold {
+    start: usize,
+    end: usize,
+    summary: String,
+}
+
+// 根据开始标签生成对应的闭合标签，例如 `<span class="x">` → `</span>`
+fn closing_tag(open_tag: &str) -> String {
+    let name = open_tag[1..]
+        .split(|c: char| c.is_whitespace() || c == '>')
+        .next()
+        .unwrap_or("span");
+    format!("</{}>", name)
+}
+
+/// 将高亮后的 HTML 按行拆分，保证每一行内的标签都是平衡的（行尾保留换行符）
+pub(crate) fn split_balanced_lines(html: &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut open_tags: Vec<&str> = Vec::new();
+    let mut current = String::new();
+    let mut has_text = false;
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if rest.starts_with("</") {
+            let end = rest.find('>').map(|i| i + 1).unwrap_or(rest.len());
+            open_tags.pop();
+            current.push_str(&rest[..end]);
+            rest = &rest[end..];
+        } else if rest.starts_with('<') {
+            let end = rest.find('>').map(|i| i + 1).unwrap_or(rest.len());
+            open_tags.push(&rest[..end]);
+            current.push_str(&rest[..end]);
+            rest = &rest[end..];
+        } else {
+            let end = rest.find(['<', '\n']).unwrap_or(rest.len());
+            if end == 0 {
+                // 换行：闭合当前行的所有标签，下一行重新打开
+                for tag in open_tags.iter().rev() {
+                    current.push_str(&closing_tag(tag));
+                }
+                current.push('\n');
+                lines.push(std::mem::take(&mut current));
+                for tag in &open_tags {
+                    current.push_str(tag);
+                }
+                has_text = false;
+                rest = &rest[1..];
+            } else {
+                current.push_str(&rest[..end]);
+                has_text = true;
+                rest = &rest[end..];
+            }
+        }
+    }
+
+    // 最后一行没有换行符，且可能只剩下闭合标签
+    if has_text {
+        lines.push(current);
+    }
+    lines
+}
+
+fn parse_folds(info: &CodeBlockInfo, line_count: usize) -> Vec<Fold> {
+    let mut folds = Vec::new();
+
+    let max_lines = info
+        .attr("maxLines")
+        .and_then(|v| v.parse::<usize>().ok())
+        .filter(|&max_lines| max_lines < line_count);
+
+    if let Some(spec) = info.attr("fold") {
+        // `maxLines` 之后的行已经在它的折叠区域中：区间截止到 `maxLines`，完全在其后的区间忽略
+        let last_line = max_lines.unwrap_or(line_count);
+        for range in spec.split(',') {
+            let Some((start, end)) = range.trim().split_once('-') else {
+                continue;
+            };
+            let (Ok(start), Ok(end)) = (start.trim().parse::<usize>(), end.trim().parse::<usize>())
+            else {
+                continue;
+            };
+            let end = end.min(last_line);
+            if start >= 1 && start <= end {
+                folds.push(Fold {
+                    start,
+                    end,
+                    summary: format!("Lines {}–{}", start, end),
+                });
+            }
+        }
+    }
+
+    if let Some(max_lines) = max_lines {
+        folds.push(Fold {
+            start: max_lines + 1,
+            end: line_count,
+            summary: format!("Show {} more lines", line_count - max_lines),
+        });
+    }
+
+    // 按起始行排序，丢弃与前一个区域重叠的 `fold` 区间
+    folds.sort_by_key(|fold| fold.start);
+    let mut merged: Vec<Fold> = Vec::new();
+    for fold in folds {
+        if merged.last().is_none_or(|last| fold.start > last.end) {
+            merged.push(fold);
+        }
+    }
+    merged
+}
+
+/// info string 中是否包含折叠相关属性
+pub(crate) fn has_folds(info: &CodeBlockInfo) -> bool {
+    info.attr("fold").is_some() || info.attr("maxLines").is_some()
+}
+
+/// 按 info string 中的折叠属性重组高亮后的 HTML，返回完整的 `<pre><code>` 输出；
+/// `code_open` 是 `<pre><code …>` 开始标签
+pub(crate) fn apply_folds(info: &CodeBlockInfo, highlighted: &str, code_open: &str) -> String {
+    let lines = split_balanced_lines(highlighted);
+    let folds = parse_folds(info, lines.len());
+    if folds.is_empty() {
+        return format!("{}{}</code></pre>", code_open, highlighted);
+    }
+
+    let mut output = String::with_capacity(highlighted.len() + folds.len() * 128);
+    let mut folds = folds.into_iter().peekable();
+    // 当前是否有未闭合的 `<pre><code>`
+    let mut in_code = false;
+
+    for (index, line) in lines.iter().enumerate() {
+        let line_number = index + 1;
+        if let Some(fold) = folds.peek().filter(|fold| fold.start == line_number) {
+            if in_code {
+                output.push_str("</code></pre>");
+                in_code = false;
+            }
+            output.push_str(&format!(
+                r#"<details class="code-fold"><summary>{}</summary>"#,
+                fold.summary
+            ));
+        }
+
+        if !in_code {
+            output.push_str(code_open);
+            in_code = true;
+        }
+        output.push_str(line);
+
+        if folds.peek().is_some_and(|fold| line_number == fold.end) {
+            output.push_str("</code></pre></details>");
+            in_code = false;
+            folds.next();
+        }
+    }
+    if in_code {
+        output.push_str("</code></pre>");
+    }
+    output
+}
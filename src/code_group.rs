@@ -0,0 +1,94 @@
+// 代码组（Tabs）：把相邻的代码块合并为一组可切换的标签页
+//
+// 两种写法：
+// 1. 相邻的代码块都带 `tab` 属性：```bash tab="npm"
+// 2. 用 `:::code-group` ... `:::` 包裹若干代码块（标签名取 tab / title / 语言）
+//
+// 输出结构（无 JS 时依靠 radio + CSS 切换）：
+// <div class="code-group">
+//   <input type="radio" class="code-group-input" ...>   每个标签一个
+//   <div class="code-group-tabs" role="tablist"><label role="tab" ...></label>...</div>
+//   <div class="code-group-panels"><div role="tabpanel" ...>代码块</div>...</div>
+// </div>
+
+use pulldown_cmark::{CowStr, Event};
+
+use crate::code_block::CodeBlockInfo;
+use crate::escape_html;
+
+struct CodeTab {
+    label: String,
+    html: String,
+}
+
+#[derive(Default)]
+pub(crate) struct CodeGroup {
+    tabs: Vec<CodeTab>,
+    // 已输出的分组数量，用于生成文档内唯一的 id
+    count: usize,
+}
+
+impl CodeGroup {
+    pub(crate) fn push(&mut self, info: &CodeBlockInfo, html: String) {
+        let label = info
+            .attr("tab")
+            .or_else(|| info.attr("title"))
+            .unwrap_or(if info.lang.is_empty() {
+                "text"
+            } else {
+                &info.lang
+            })
+            .to_string();
+        self.tabs.push(CodeTab { label, html });
+    }
+
+    /// 渲染当前分组并输出到事件流，然后清空分组
+    pub(crate) fn flush(&mut self, events: &mut Vec<Event<'_>>) {
+        if self.tabs.is_empty() {
+            return;
+        }
+        self.count += 1;
+        let html = self.render();
+        self.tabs.clear();
+        events.push(Event::Html(CowStr::from(html)));
+    }
+
+    fn render(&self) -> String {
+        let group_id = format!("code-group-{}", self.count);
+
+        let mut inputs = String::new();
+        let mut labels = String::new();
+        let mut panels = String::new();
+
+        for (i, tab) in self.tabs.iter().enumerate() {
+            let input_id = format!("{}-input-{}", group_id, i);
+            let tab_id = format!("{}-tab-{}", group_id, i);
+            let panel_id = format!("{}-panel-{}", group_id, i);
+            let selected = i == 0;
+
+            inputs.push_str(&format!(
+                r#"<input type="radio" class="code-group-input" name="{}" id="{}"{}>"#,
+                group_id,
+                input_id,
+                if selected { " checked" } else { "" }
+            ));
+            labels.push_str(&format!(
+                r#"<label for="{}" id="{}" role="tab" aria-selected="{}" aria-controls="{}">{}</label>"#,
+                input_id,
+                tab_id,
+                selected,
+                panel_id,
+                escape_html(&tab.label)
+            ));
+            panels.push_str(&format!(
+                r#"<div class="code-group-panel" id="{}" role="tabpanel" aria-labelledby="{}">{}</div>"#,
+                panel_id, tab_id, tab.html
+            ));
+        }
+
+        format!(
+            r#"<div class="code-group">{}<div class="code-group-tabs" role="tablist">{}</div><div class="code-group-panels">{}</div></div>"#,
+            inputs, labels, panels
+        )
+    }
+}
@@ -0,0 +1,235 @@
+// `:::` 容器（VuePress / markdown-it-container 风格）
+//
+// ::: tip 可选标题
+// 内容（完整的 Markdown：公式、高亮代码、嵌套容器……）
+// :::
+//
+// 容器标记通常紧挨着内容，不会单独成为段落，所以在解析前逐行扫描源文本（跳过围栏代码块），
+// 把开始/结束标记行替换为前后带空行的 HTML 块，内容仍然交给 pulldown-cmark 正常解析：
+// <div class="custom-container tip">
+// <p class="custom-container-title">TIP</p>
+//
+// 内容
+//
+// </div>
+//
+// 规则：
+// - 开始标记为行首（最多 3 个空格缩进）的 3 个以上 `:`，后接容器名与可选标题
+// - 标记行在列表项或引用块中时（`  ::: tip`、`> ::: tip`），生成的 HTML 与空行带上同样的缩进与 `>`，
+//   容器留在列表项 / 引用块内
+// - 结束标记为只包含 `:` 的行，数量不少于对应的开始标记；嵌套时外层可以用更多的 `:`
+// - 未注册的容器名保持原文；`::: code-group` 由代码组单独处理
+
+use std::collections::HashMap;
+
+use crate::escape_html;
+use crate::options::Container;
+
+// 内置容器，输出与 VuePress 2 相同
+fn builtin_container(name: &str) -> Option<Container> {
+    let (element, title) = match name {
+        "tip" => ("div", "TIP"),
+        "warning" => ("div", "WARNING"),
+        "danger" => ("div", "DANGER"),
+        "details" => ("details", "Details"),
+        _ => return None,
+    };
+    Some(Container {
+        element: element.to_string(),
+        class: String::new(),
+        title: Some(title.to_string()),
+    })
+}
+
+// 元素名只允许字母开头的字母、数字与 `-`，否则使用 div
+fn element_name(element: &str) -> &str {
+    let valid = element.starts_with(|c: char| c.is_ascii_alphabetic())
+        && element
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-');
+    if valid { element } else { "div" }
+}
+
+// 标记行的前缀（缩进与引用块的 `>`，每层最多 3 个空格缩进）、`:` 的数量，以及 `:` 之后的内容
+fn colon_marker(line: &str) -> Option<(&str, usize, &str)> {
+    let mut prefix_len = 0;
+    loop {
+        let rest = &line[prefix_len..];
+        let trimmed = rest.trim_start_matches(' ');
+        if rest.len() - trimmed.len() > 3 {
+            return None;
+        }
+        prefix_len += rest.len() - trimmed.len();
+        match trimmed.strip_prefix('>') {
+            Some(after) => prefix_len += 1 + usize::from(after.starts_with(' ')),
+            None => break,
+        }
+    }
+    let (prefix, trimmed) = line.split_at(prefix_len);
+    let colons = trimmed.len() - trimmed.trim_start_matches(':').len();
+    (colons >= 3).then(|| (prefix, colons, trimmed[colons..].trim()))
+}
+
+// 生成的 HTML 每一行加上标记行的前缀；空行只保留 `>`，使引用块不会中断
+fn with_prefix(html: &str, prefix: &str) -> String {
+    if prefix.is_empty() {
+        return html.to_string();
+    }
+    let mut output = String::with_capacity(html.len() + prefix.len() * 4);
+    for line in html.split_inclusive('\n') {
+        if line == "\n" {
+            output.push_str(prefix.trim_end());
+        } else {
+            output.push_str(prefix);
+        }
+        output.push_str(line);
+    }
+    output
+}
+
+/// 段落文本是否为代码组容器的标记：开始标记 `:::code-group` / `::: code-group`，
+/// 已在代码组中时还包括结束标记 `:::`
+pub(crate) fn is_code_group_marker(text: &str, in_code_group: bool) -> bool {
+    colon_marker(text)
+        .is_some_and(|(_, _, rest)| rest == "code-group" || (in_code_group && rest.is_empty()))
+}
+
+// 围栏代码块的开始标记：(字符, 数量)
+fn code_fence(line: &str) -> Option<(char, usize)> {
+    let trimmed = line.trim_start_matches(' ');
+    if line.len() - trimmed.len() > 3 {
+        return None;
+    }
+    let c = trimmed.chars().next().filter(|&c| c == '`' || c == '~')?;
+    let count = trimmed.len() - trimmed.trim_start_matches(c).len();
+    (count >= 3).then_some((c, count))
+}
+
+/// 逐行遍历源文本，返回 (行, 是否在围栏代码块中)；围栏标记行本身也算在代码块中
+pub(crate) fn source_lines(source: &str) -> impl Iterator<Item = (&str, bool)> {
+    let mut fence: Option<(char, usize)> = None;
+    source.split_inclusive('\n').map(move |line| {
+        if let Some((c, count)) = fence {
+            if code_fence(line).is_some_and(|(close, n)| {
+                close == c && n >= count && line.trim().trim_start_matches(c).is_empty()
+            }) {
+                fence = None;
+            }
+            return (line, true);
+        }
+        if let Some(open) = code_fence(line) {
+            fence = Some(open);
+            return (line, true);
+        }
+        (line, false)
+    })
+}
+
+fn render_open(name: &str, container: &Container, title: &str) -> String {
+    let element = element_name(&container.element);
+    let class = if container.class.is_empty() {
+        format!("custom-container {}", name)
+    } else {
+        container.class.clone()
+    };
+
+    let mut html = format!("<{} class=\"{}\">\n", element, escape_html(&class));
+    let title = if title.is_empty() {
+        container.title.as_deref()
+    } else {
+        Some(title)
+    };
+    if let Some(title) = title {
+        if element == "details" {
+            html.push_str(&format!("<summary>{}</summary>\n", escape_html(title)));
+        } else {
+            html.push_str(&format!(
+                "<p class=\"custom-container-title\">{}</p>\n",
+                escape_html(title)
+            ));
+        }
+    }
+    // 空行结束 HTML 块，之后的内容按 Markdown 解析
+    html.push('\n');
+    html
+}
+
+/// 把源文本中的容器标记替换为 HTML 块；没有容器时返回 `None`
+pub(crate) fn expand_containers(
+    source: &str,
+    containers: &HashMap<String, Container>,
+) -> Option<String> {
+    if !source.contains(":::") {
+        return None;
+    }
+
+    let mut output = String::with_capacity(source.len());
+    // 已打开的容器：(冒号数量, 结束标签, 标记行前缀)，保留原文的容器结束标签为 None
+    let mut stack: Vec<(usize, Option<String>, &str)> = Vec::new();
+    let mut changed = false;
+
+    for (line, in_code) in source_lines(source) {
+        // 围栏代码块内的内容原样保留
+        if in_code {
+            output.push_str(line);
+            continue;
+        }
+
+        let Some((prefix, colons, rest)) = colon_marker(line) else {
+            output.push_str(line);
+            continue;
+        };
+
+        // 结束标记
+        if rest.is_empty() {
+            match stack.last() {
+                Some((open_colons, ..)) if colons >= *open_colons => {
+                    if let Some((_, Some(close), open_prefix)) = stack.pop() {
+                        output.push_str(&with_prefix(&format!("\n{}\n\n", close), open_prefix));
+                        changed = true;
+                    } else {
+                        output.push_str(line);
+                    }
+                }
+                _ => output.push_str(line),
+            }
+            continue;
+        }
+
+        // 开始标记：`::: name 标题`
+        // 代码组与未注册的容器保留原文，但仍然入栈，使结束标记与开始标记正确配对
+        let (name, title) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+        let container = containers
+            .get(name)
+            .cloned()
+            .or_else(|| builtin_container(name))
+            .filter(|_| name != "code-group");
+        match container {
+            Some(container) => {
+                output.push_str(&with_prefix(
+                    &format!("\n{}", render_open(name, &container, title.trim())),
+                    prefix,
+                ));
+                stack.push((
+                    colons,
+                    Some(format!("</{}>", element_name(&container.element))),
+                    prefix,
+                ));
+                changed = true;
+            }
+            None => {
+                stack.push((colons, None, prefix));
+                output.push_str(line);
+            }
+        }
+    }
+
+    // 没有结束标记的容器在文档末尾关闭
+    while let Some((_, close, prefix)) = stack.pop() {
+        if let Some(close) = close {
+            output.push_str(&with_prefix(&format!("\n\n{}\n", close), prefix));
+        }
+    }
+
+    changed.then_some(output)
+}
@@ -0,0 +1,83 @@
+// Emoji 短代码：`:tada:`、`:+1:` 等 GitHub（gemoji）短代码
+//
+// 短代码表（`table.rs`）编译进二进制，按短代码排序后二分查找。
+// - 短代码由小写字母、数字与 `_` `+` `-` 组成，表中没有的 `:name:` 保持原文
+// - 匹配失败时从第二个 `:` 继续查找，`10:30:tada:` 中的 `:tada:` 仍然替换
+// - 默认输出 Unicode emoji；配置 `image_url` 时输出 `<img class="emoji">`，
+//   模板中的 `{name}` 替换为短代码，`{unicode}` 替换为小写十六进制码位（去掉 U+FE0F，以 `-` 连接，
+//   例如 `1f44d`、`23-20e3`，与 Twemoji 的文件名相同）
+
+mod table;
+
+use std::ops::Range;
+
+use crate::escape_html;
+
+/// 文本中识别出的一个短代码
+pub(crate) struct Shortcode {
+    /// 包括两端 `:` 的范围
+    pub(crate) range: Range<usize>,
+    pub(crate) name: &'static str,
+    pub(crate) emoji: &'static str,
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '_' | '+' | '-')
+}
+
+fn lookup(name: &str) -> Option<(&'static str, &'static str)> {
+    table::EMOJI
+        .binary_search_by(|(shortcode, _)| (*shortcode).cmp(name))
+        .ok()
+        .map(|index| table::EMOJI[index])
+}
+
+/// 查找文本中的短代码，按出现顺序返回
+pub(crate) fn find(text: &str) -> Vec<Shortcode> {
+    let mut found = Vec::new();
+    let mut start = 0;
+    while let Some(open) = text[start..].find(':').map(|i| start + i) {
+        let name_start = open + 1;
+        let name_end = text[name_start..]
+            .find(|c: char| !is_name_char(c))
+            .map_or(text.len(), |len| name_start + len);
+        let shortcode = if name_end > name_start && text[name_end..].starts_with(':') {
+            lookup(&text[name_start..name_end])
+        } else {
+            None
+        };
+
+        match shortcode {
+            Some((name, emoji)) => {
+                found.push(Shortcode {
+                    range: open..name_end + 1,
+                    name,
+                    emoji,
+                });
+                start = name_end + 1;
+            }
+            None => start = name_start,
+        }
+    }
+    found
+}
+
+/// 使用图片模板输出短代码
+pub(crate) fn render_image(shortcode: &Shortcode, template: &str) -> String {
+    let unicode = shortcode
+        .emoji
+        .chars()
+        .filter(|&c| c != '\u{FE0F}')
+        .map(|c| format!("{:x}", c as u32))
+        .collect::<Vec<_>>()
+        .join("-");
+    let src = template
+        .replace("{name}", shortcode.name)
+        .replace("{unicode}", &unicode);
+    format!(
+        "<img class=\"emoji\" title=\":{}:\" alt=\"{}\" src=\"{}\" height=\"20\" width=\"20\">",
+        shortcode.name,
+        shortcode.emoji,
+        escape_html(&src)
+    )
+}
@@ -0,0 +1,86 @@
+// 标题锚点：为标题生成 GitHub 风格的 id（slug）
+//
+// slug 取自标题的源文本而不是渲染结果：公式使用 LaTeX 源码（`## $E=mc^2$` → `emc2`），
+// 不会把 MathML 标签混进 id。规则与 GitHub 相同：
+// 1. 转为小写
+// 2. 去掉字母、数字、空格、`-`、`_` 以外的字符
+// 3. 空格替换为 `-`
+// 4. 重复的 slug 依次加上 `-1`、`-2` 后缀
+
+use std::collections::HashMap;
+
+use pulldown_cmark::{CowStr, Event, Options, Parser};
+
+use crate::math::delimiters::{self, Formula};
+
+/// 标题的纯文本：重新解析标题源码，公式（包括占位符中的）取 LaTeX 源码
+pub(crate) fn heading_text(source: &str, options: Options, formulas: &[Formula]) -> String {
+    let mut events = Vec::new();
+    for event in Parser::new_ext(source, options) {
+        match event {
+            Event::Text(text) => delimiters::push_text_with_placeholders(
+                text,
+                formulas,
+                &mut events,
+                |formula| formula.latex.clone(),
+                |text, events| events.push(Event::Text(text)),
+            ),
+            Event::Code(text) | Event::InlineMath(text) | Event::DisplayMath(text) => {
+                events.push(Event::Text(text))
+            }
+            Event::SoftBreak | Event::HardBreak => events.push(Event::Text(CowStr::from(" "))),
+            _ => {}
+        }
+    }
+
+    let mut text = String::new();
+    for event in events {
+        if let Event::Text(part) | Event::Html(part) = event {
+            text.push_str(&part);
+        }
+    }
+    text
+}
+
+/// 文档内的 slug 生成器，记录已使用的 slug 以保证唯一
+#[derive(Default)]
+pub(crate) struct Slugger {
+    used: HashMap<String, usize>,
+}
+
+impl Slugger {
+    /// 登记显式指定的 id，自动生成的 slug 不会再使用它
+    pub(crate) fn reserve(&mut self, id: &str) {
+        self.used.entry(id.to_string()).or_insert(0);
+    }
+
+    pub(crate) fn slug(&mut self, text: &str) -> String {
+        let base: String = text
+            .trim()
+            .to_lowercase()
+            .chars()
+            .filter_map(|c| match c {
+                ' ' => Some('-'),
+                '-' | '_' => Some(c),
+                _ if c.is_alphanumeric() => Some(c),
+                _ => None,
+            })
+            .collect();
+
+        let mut slug = base.clone();
+        if let Some(&count) = self.used.get(&base) {
+            // 跳过已经被其他标题占用的后缀（如标题本身就是 `foo-1`）
+            let mut count = count;
+            loop {
+                count += 1;
+                slug = format!("{}-{}", base, count);
+                if !self.used.contains_key(&slug) {
+                    break;
+                }
+            }
+            self.used.insert(base, count);
+        }
+        self.used.insert(slug.clone(), 0);
+        slug
+    }
+}
@@ -1,111 +1,251 @@
-use latex2mathml::{DisplayStyle, latex_to_mathml};
 use once_cell::sync::Lazy;
-use pulldown_cmark::{CodeBlockKind, CowStr, Event, Options, Parser, Tag, TagEnd, html};
-use regex::Regex;
+use pulldown_cmark::{CodeBlockKind, CowStr, Event, LinkType, Options, Parser, Tag, TagEnd, html};
 
-use syntect::html::{ClassStyle, ClassedHTMLGenerator};
 use syntect::parsing::SyntaxSet;
-use syntect::util::LinesWithEndings;
 use wasm_bindgen::prelude::*;
 
+mod abbreviation;
+mod alert;
+mod ansi;
+mod autolink;
+mod code_block;
+mod code_fold;
+mod code_group;
+mod container;
+mod emoji;
+mod heading;
+mod math;
+mod options;
+mod quotes;
+mod reference;
+mod result;
+mod shell;
+mod text;
+mod wikilink;
+
+use code_block::{CodeBlockInfo, render_code_block};
+use code_group::CodeGroup;
+use heading::Slugger;
+use math::MathRenderer;
+pub use options::{
+    Container, DollarRule, EmojiOptions, EquationNumbers, MathAltText, MathDelimiters, MathOptions,
+    MathOutput, MathParser, ReferenceOptions, RenderOptions,
+};
+pub use result::{Reference, RenderResult};
+pub use wikilink::{WikiLinkResolver, WikiLinkTarget};
+
 // --- 1. 静态资源预加载 ---
 static SYNTAX_SET: Lazy<SyntaxSet> = Lazy::new(|| {
     let syntax_dump = include_bytes!("../assets/syntax.packdump");
     syntect::dumps::from_binary(syntax_dump)
 });
 
-// 预编译正则：GitHub 风格数学公式检测
-// 规则：
-// - $$...$$ : 块级公式
-// - $...$   : 行内公式，要求：
-//   1. 第一个 $ 前是字符串开头或空格
-//   2. 第二个 $ 后是字符串结尾或空格
-// 这样可区分 $5/month (货币) 和 $1+1=2$ (公式)
-// 正则不包含边界，边界检查在代码中进行
-static MATH_REGEX: Lazy<Regex> =
-    Lazy::new(|| Regex::new(r"(\$\$)([\s\S]+?)(\$\$)|\$([^$\s][^$]*?)\$").unwrap());
-
-// --- 2. 辅助函数：数学渲染 ---
-fn render_math(latex: &str, display_mode: bool) -> String {
-    let style = if display_mode {
-        DisplayStyle::Block
-    } else {
-        DisplayStyle::Inline
-    };
-    match latex_to_mathml(latex, style) {
-        Ok(mathml) => mathml,
-        Err(_) => {
-            format!(
-                r#"<span class="math-error" style="color:red">Error: {}</span>"#,
-                latex
-            )
+// --- 2. 辅助函数：HTML 转义（用于手动拼接的 HTML 片段） ---
+pub(crate) fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
         }
     }
+    escaped
 }
 
 // --- 3. 核心导出函数 ---
 #[wasm_bindgen]
 pub fn render_markdown(markdown_input: &str) -> String {
+    render_markdown_with_options(markdown_input, &RenderOptions::default())
+}
+
+// JS 侧入口：选项以 JSON 字符串传入
+#[wasm_bindgen(js_name = render_markdown_with_options)]
+pub fn render_markdown_with_options_json(
+    markdown_input: &str,
+    options_json: &str,
+) -> Result<String, JsError> {
+    let options = RenderOptions::from_json(options_json)
+        .map_err(|e| JsError::new(&format!("Invalid render options: {}", e)))?;
+    Ok(render_markdown_with_options(markdown_input, &options))
+}
+
+// JS 侧入口：返回 `RenderResult` 的 JSON 字符串
+#[wasm_bindgen(js_name = render_markdown_with_metadata)]
+pub fn render_markdown_with_metadata_json(
+    markdown_input: &str,
+    options_json: &str,
+) -> Result<String, JsError> {
+    let options = RenderOptions::from_json(options_json)
+        .map_err(|e| JsError::new(&format!("Invalid render options: {}", e)))?;
+    let result = render_markdown_with_metadata(markdown_input, &options);
+    serde_json::to_string(&result).map_err(|e| JsError::new(&e.to_string()))
+}
+
+// JS 侧入口：Wiki 链接由回调解析，返回 `RenderResult` 的 JSON 字符串
+#[wasm_bindgen(js_name = render_markdown_with_resolver)]
+pub fn render_markdown_with_resolver_json(
+    markdown_input: &str,
+    options_json: &str,
+    resolver: &wikilink::WikiLinkCallback,
+) -> Result<String, JsError> {
+    let options = RenderOptions::from_json(options_json)
+        .map_err(|e| JsError::new(&format!("Invalid render options: {}", e)))?;
+    let result = render_markdown_with_resolver(markdown_input, &options, resolver);
+    serde_json::to_string(&result).map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// 使用自定义选项渲染 Markdown，`render_markdown` 等价于使用默认选项调用本函数
+pub fn render_markdown_with_options(
+    markdown_input: &str,
+    render_options: &RenderOptions,
+) -> String {
+    render_markdown_with_metadata(markdown_input, render_options).html
+}
+
+/// 渲染 Markdown，同时返回渲染过程中收集到的元数据（如未解析的公式引用）
+pub fn render_markdown_with_metadata(
+    markdown_input: &str,
+    render_options: &RenderOptions,
+) -> RenderResult {
+    // 没有解析器时 Wiki 链接的 href 就是页面名
+    let resolver = |page: &str| WikiLinkTarget {
+        href: page.to_string(),
+        exists: true,
+    };
+    render_markdown_with_resolver(markdown_input, render_options, &resolver)
+}
+
+/// 与 `render_markdown_with_metadata` 相同，Wiki 链接（`wikilinks` 选项）的页面名由 `resolver` 解析
+pub fn render_markdown_with_resolver(
+    markdown_input: &str,
+    render_options: &RenderOptions,
+    resolver: &dyn WikiLinkResolver,
+) -> RenderResult {
     // A. 开启 Markdown 选项 (GFM)
     let mut options = Options::empty();
     options.insert(Options::ENABLE_TABLES);
     options.insert(Options::ENABLE_FOOTNOTES);
     options.insert(Options::ENABLE_STRIKETHROUGH);
     options.insert(Options::ENABLE_TASKLISTS);
+    // GitHub 提示块 `> [!NOTE]` 等
+    options.insert(Options::ENABLE_GFM);
+    if render_options.heading_attributes {
+        options.insert(Options::ENABLE_HEADING_ATTRIBUTES);
+    }
+    if render_options.definition_lists {
+        options.insert(Options::ENABLE_DEFINITION_LIST);
+    }
+    if render_options.superscript {
+        options.insert(Options::ENABLE_SUPERSCRIPT);
+    }
+    if render_options.subscript {
+        options.insert(Options::ENABLE_SUBSCRIPT);
+    }
+    if render_options.smart_punctuation {
+        options.insert(Options::ENABLE_SMART_PUNCTUATION);
+    }
+    if render_options.wikilinks {
+        options.insert(Options::ENABLE_WIKILINKS);
+    }
+
+    let math_options = &render_options.math;
+    let native_math = math_options.parser == MathParser::Native;
+    if native_math {
+        options.insert(Options::ENABLE_MATH);
+    }
+
+    // `:::` 容器标记在解析前替换为 HTML 块
+    let expanded_input = container::expand_containers(markdown_input, &render_options.containers);
+    let markdown_input = expanded_input.as_deref().unwrap_or(markdown_input);
+
+    // 缩写定义行（`*[HTML]: ...`）在解析前移除
+    let abbreviated_input = render_options
+        .abbreviations
+        .then(|| abbreviation::extract_abbreviations(markdown_input))
+        .flatten();
+    let (markdown_input, abbreviations) = match &abbreviated_input {
+        Some((input, abbreviations)) => (input.as_str(), abbreviations.as_slice()),
+        None => (markdown_input, &[][..]),
+    };
+
+    // 额外的公式分隔符（\(...\)、\[...\]、$`...`$）在解析前替换为占位符
+    let delimiters = &math_options.delimiters;
+    let (prepared_input, formulas) =
+        math::delimiters::extract(markdown_input, math_options, options)
+            .unwrap_or_else(|| (markdown_input.to_string(), Vec::new()));
+    let markdown_input = prepared_input.as_str();
+    let math_renderer = MathRenderer::new(math_options);
 
     // B. 创建解析器
     let parser = Parser::new_ext(markdown_input, options);
 
     // C. 状态机变量
     let mut in_code_block = false;
-    let mut current_lang = None;
+    let mut current_info = CodeBlockInfo::default();
     let mut code_buffer = String::new();
 
+    // 代码组：相邻的带 tab 属性的代码块，或 ::: code-group 容器中的代码块
+    let mut code_group = CodeGroup::default();
+    let mut in_code_group_container = false;
+    let mut skip_paragraph_end = false;
+
     let mut in_display_math = false;
     let mut math_buffer = String::new();
+    let mut math_text_end = None;
+
+    // 相邻的 Text 事件（被转义字符、智能标点等拆开）合并后再查找公式与自动链接
+    let mut pending_text = String::new();
+    let mut text_pass = text::TextPass::new(
+        &render_options.references,
+        &render_options.emoji,
+        abbreviations,
+    );
+    let mut wikilinks = wikilink::WikiLinks::new(resolver);
+
+    let mut slugger = Slugger::default();
+    // 显式 id（`{#id}`）可能出现在后面的标题中，先全部登记，避免自动 slug 与之重复
+    if render_options.heading_ids && render_options.heading_attributes {
+        for event in Parser::new_ext(markdown_input, options) {
+            if let Event::Start(Tag::Heading { id: Some(id), .. }) = event {
+                slugger.reserve(&id);
+            }
+        }
+    }
+
+    // 智能引号的语言区域（英文引号由 pulldown-cmark 直接生成，不需要替换）
+    let mut locale_quotes = render_options
+        .smart_punctuation
+        .then(|| quotes::LocaleQuotes::new(&render_options.locale))
+        .flatten();
 
     // D. 事件流处理
     let mut new_events = Vec::new();
 
-    for event in parser {
+    for (event, range) in parser.into_offset_iter() {
         // --- State 1: Inside Code Block ---
         // 必须优先处理，捕获所有内容
         if in_code_block {
             match event {
                 Event::End(TagEnd::CodeBlock) => {
                     in_code_block = false;
-                    let lang = current_lang.as_deref().unwrap_or("text");
+                    let lang = current_info.lang.as_str();
 
                     if lang == "math" || lang == "latex" {
-                        let math_html = render_math(&code_buffer, true);
+                        let math_html = math_renderer.render(&code_buffer, true);
+                        code_group.flush(&mut new_events);
                         new_events.push(Event::Html(CowStr::from(math_html)));
                     } else {
-                        let ss = &SYNTAX_SET;
-                        // 语言标识符映射：将一些常见的别名映射到正确的语法
-                        let lang_normalized = match lang {
-                            "jsx" => "tsx",  // JSX 使用 TSX 语法高亮
-                            _ => lang,
-                        };
-                        let syntax = ss
-                            .find_syntax_by_token(lang_normalized)
-                            .unwrap_or_else(|| ss.find_syntax_plain_text());
-
-                        let mut html_generator = ClassedHTMLGenerator::new_with_class_style(
-                            syntax,
-                            ss,
-                            ClassStyle::Spaced,
-                        );
-
-                        for line in LinesWithEndings::from(&code_buffer) {
-                            let _ = html_generator.parse_html_for_line_which_includes_newline(line);
+                        let html = render_code_block(&current_info, &code_buffer, render_options);
+                        if in_code_group_container || current_info.attr("tab").is_some() {
+                            code_group.push(&current_info, html);
+                        } else {
+                            code_group.flush(&mut new_events);
+                            new_events.push(Event::Html(CowStr::from(html)));
                         }
-
-                        let highlighted_code = html_generator.finalize();
-                        let html = format!(
-                            r#"<pre><code class="language-{}">{}</code></pre>"#,
-                            lang, highlighted_code
-                        );
-                        new_events.push(Event::Html(CowStr::from(html)));
                     }
                     code_buffer.clear();
                 }
@@ -120,11 +260,16 @@ pub fn render_markdown(markdown_input: &str) -> String {
         // 处理多行公式块
         if in_display_math {
             match event {
-                Event::Text(text) => {
+                // 使用源码而不是 Text 的内容：Markdown 转义会把换行符 `\\` 变成 `\`，
+                // 转义字符的 Text 范围只包含第二个字符，所以从上一个相邻 Text 的结尾开始截取
+                Event::Text(_) => {
+                    let start = math_text_end.unwrap_or(range.start);
+                    math_text_end = Some(range.end);
+                    let text = &markdown_input[start..range.end];
                     if let Some(idx) = text.find("$$") {
                         // 结束 Display Math
                         math_buffer.push_str(&text[..idx]);
-                        let math_html = render_math(&math_buffer, true);
+                        let math_html = math_renderer.render(&math_buffer, true);
                         new_events.push(Event::Html(CowStr::from(math_html)));
 
                         math_buffer.clear();
@@ -135,121 +280,222 @@ pub fn render_markdown(markdown_input: &str) -> String {
                             new_events.push(Event::Text(CowStr::from(text[idx + 2..].to_string())));
                         }
                     } else {
-                        math_buffer.push_str(&text);
+                        math_buffer.push_str(text);
                     }
                 }
-                Event::SoftBreak | Event::HardBreak => math_buffer.push('\n'),
-                _ => {}
+                Event::SoftBreak | Event::HardBreak => {
+                    math_text_end = None;
+                    math_buffer.push('\n');
+                }
+                _ => math_text_end = None,
             }
             continue;
         }
 
         // --- State 3: Normal State ---
+        // 智能引号按语言区域替换（代码块与多行公式已在前面的状态中处理，不会到达这里）
+        let event = match (&mut locale_quotes, event) {
+            (Some(quotes), Event::Text(text)) => {
+                match quotes.replace(&text, &markdown_input[range.clone()]) {
+                    Some(quote) => Event::Text(CowStr::Borrowed(quote)),
+                    None => Event::Text(text),
+                }
+            }
+            (Some(quotes), event) => {
+                if let Event::Start(
+                    Tag::Paragraph | Tag::Heading { .. } | Tag::TableCell | Tag::Item,
+                ) = event
+                {
+                    quotes.reset();
+                }
+                event
+            }
+            (None, event) => event,
+        };
+
+        if !matches!(event, Event::Text(_)) && !pending_text.is_empty() {
+            flush_pending_text(
+                &mut pending_text,
+                &formulas,
+                &math_renderer,
+                &mut text_pass,
+                &mut new_events,
+            );
+        }
+
+        text_pass.observe(&event);
+
+        // 相邻代码块之外的任何事件都会结束当前代码组，保持输出顺序与源文本一致
+        // （`::: code-group` 容器中夹在代码块之间的段落会把容器分成两组）
+        if !matches!(event, Event::Start(Tag::CodeBlock(_))) {
+            code_group.flush(&mut new_events);
+        }
+
         match event {
             Event::Start(Tag::CodeBlock(kind)) => {
                 in_code_block = true;
-                current_lang = match kind {
-                    CodeBlockKind::Fenced(lang) => Some(lang.to_string()),
-                    CodeBlockKind::Indented => None,
+                current_info = match kind {
+                    CodeBlockKind::Fenced(info) => CodeBlockInfo::parse(&info),
+                    CodeBlockKind::Indented => CodeBlockInfo::default(),
                 };
             }
 
+            // ::: code-group 容器的开始/结束标记各自独占一个段落，连同段落标签一起移除
+            Event::End(TagEnd::Paragraph) if skip_paragraph_end => {
+                skip_paragraph_end = false;
+            }
+            Event::Text(text)
+                if matches!(new_events.last(), Some(Event::Start(Tag::Paragraph)))
+                    && container::is_code_group_marker(&text, in_code_group_container) =>
+            {
+                new_events.pop();
+                skip_paragraph_end = true;
+                in_code_group_container = !in_code_group_container;
+            }
+
+            // --- Native 模式: pulldown-cmark 已经识别好的公式 ---
+            Event::InlineMath(latex) => {
+                let prev_char = markdown_input[..range.start].chars().last();
+                let next_char = markdown_input[range.end..].chars().next();
+                let accepted = math::dollars::accepts_inline(
+                    math_options.dollar_rule,
+                    math_options.github_boundaries,
+                    prev_char,
+                    &latex,
+                    next_char,
+                );
+                if delimiters.dollars && accepted {
+                    new_events.push(Event::Html(CowStr::from(
+                        math_renderer.render(&latex, false),
+                    )));
+                } else {
+                    // 未启用 $ 或不满足 `dollar_rule`（如 a$x$b），保留原文
+                    new_events.push(Event::Text(CowStr::from(&markdown_input[range])));
+                }
+            }
+            Event::DisplayMath(latex) => {
+                if delimiters.double_dollars {
+                    new_events.push(Event::Html(CowStr::from(
+                        math_renderer.render(&latex, true),
+                    )));
+                } else {
+                    new_events.push(Event::Text(CowStr::from(&markdown_input[range])));
+                }
+            }
+            Event::Text(text) if native_math => pending_text.push_str(&text),
+
             // --- 核心修改: 使用正则处理 Text 中的数学公式 ---
             Event::Text(text) => {
                 // 1. [修复] 检查是否是多行公式块的开始 ($$)
                 // 如果当前文本行纯粹是 "$$"，则切换到 display_math 模式。
                 // 这解决了 test_math_block 失败的问题。
-                if text.trim() == "$$" {
+                if delimiters.double_dollars && pending_text.is_empty() && text.trim() == "$$" {
                     in_display_math = true;
+                    math_text_end = None;
                     continue;
                 }
 
-                // 如果没有 $ 符号，直接跳过正则，提升性能
-                if !text.contains('$') {
-                    new_events.push(Event::Text(text));
-                    continue;
+                // 2. 被转义的字符（如 `\$`、`\{`）单独成为一个 Text 事件，范围不包含反斜杠
+                let backslashes = markdown_input[..range.start]
+                    .bytes()
+                    .rev()
+                    .take_while(|&b| b == b'\\')
+                    .count();
+                if backslashes % 2 == 1 && range.len() == text.len() {
+                    pending_text.push(math::dollars::ESCAPE);
                 }
+                pending_text.push_str(&text);
+            }
 
-                let mut last_end = 0;
-
-                // 遍历所有正则匹配项 (处理行内公式和单行块级公式)
-                for cap in MATH_REGEX.captures_iter(&text) {
-                    let match_start = cap.get(0).unwrap().start();
-                    let match_end = cap.get(0).unwrap().end();
-
-                    // 1. 将匹配项之前的普通文本 push 进去
-                    if match_start > last_end {
-                        new_events.push(Event::Text(CowStr::from(
-                            text[last_end..match_start].to_string(),
-                        )));
-                    }
-
-                    // 2. 判断是 $$ 还是 $
-                    if let Some(content) = cap.get(2) {
-                        // 匹配到了 $$ (Group 2 是内容)
-                        let math_html = render_math(content.as_str(), true);
-                        new_events.push(Event::Html(CowStr::from(math_html)));
-                    } else if let Some(content) = cap.get(4) {
-                        // 匹配到了 $ (Group 4 是内容)
-                        // GitHub 风格边界检查（扩展支持中文）：
-                        // - 前缀：字符串开头、空白字符、或非 ASCII 字符（如中文）
-                        // - 后缀：字符串结尾、空白字符、标点符号、或非 ASCII 字符
-                        let prev_char = text[..match_start].chars().last();
-                        let is_valid_prefix = match_start == 0
-                            || prev_char
-                                .map(|c| c.is_whitespace() || !c.is_ascii_alphanumeric())
-                                .unwrap_or(false);
-
-                        let next_char = text[match_end..].chars().next();
-                        let is_valid_suffix = match_end == text.len()
-                            || next_char
-                                .map(|c| {
-                                    c.is_whitespace()
-                                        || !c.is_ascii_alphanumeric()
-                                        || matches!(
-                                            c,
-                                            '.' | ','
-                                                | ';'
-                                                | ':'
-                                                | '!'
-                                                | '?'
-                                                | ')'
-                                                | ']'
-                                                | '}'
-                                                | '"'
-                                                | '\''
-                                                | '/'
-                                        )
-                                })
-                                .unwrap_or(false);
-
-                        if is_valid_prefix && is_valid_suffix {
-                            // 是有效的公式，渲染它
-                            let math_html = render_math(content.as_str(), false);
-                            new_events.push(Event::Html(CowStr::from(math_html)));
-                        } else {
-                            // 不满足边界条件（如 $5/month），当作普通文本
-                            new_events.push(Event::Text(CowStr::from(
-                                cap.get(0).unwrap().as_str().to_string(),
-                            )));
-                        }
-                    }
+            // GitHub 提示块：替换 <blockquote> 为 github.com 的 markdown-alert 结构
+            Event::Start(Tag::BlockQuote(Some(kind))) => {
+                new_events.push(Event::Html(CowStr::from(alert::render_alert_start(kind))))
+            }
+            Event::End(TagEnd::BlockQuote(Some(_))) => {
+                new_events.push(Event::Html(CowStr::from(alert::render_alert_end())))
+            }
 
-                    last_end = match_end;
-                }
+            // 标题锚点：slug 取自标题源文本，公式使用 LaTeX 源码而不是 MathML；
+            // 已有显式 id 的标题（`{#id}`）不会进入这里
+            Event::Start(Tag::Heading {
+                level,
+                id: None,
+                classes,
+                attrs,
+            }) if render_options.heading_ids => {
+                let text = heading::heading_text(&markdown_input[range], options, &formulas);
+                new_events.push(Event::Start(Tag::Heading {
+                    level,
+                    id: Some(CowStr::from(slugger.slug(&text))),
+                    classes,
+                    attrs,
+                }));
+            }
 
-                // 3. 将剩余的普通文本 push 进去
-                if last_end < text.len() {
-                    new_events.push(Event::Text(CowStr::from(text[last_end..].to_string())));
+            Event::Start(
+                tag @ (Tag::Link {
+                    link_type: LinkType::WikiLink { .. },
+                    ..
                 }
-            }
+                | Tag::Image {
+                    link_type: LinkType::WikiLink { .. },
+                    ..
+                }),
+            ) => new_events.push(wikilinks.start(tag)),
+            Event::End(TagEnd::Link) => new_events.push(wikilinks.end_link()),
 
             _ => new_events.push(event),
         }
     }
 
-    // E. 最终渲染
+    if !pending_text.is_empty() {
+        flush_pending_text(
+            &mut pending_text,
+            &formulas,
+            &math_renderer,
+            &mut text_pass,
+            &mut new_events,
+        );
+    }
+    code_group.flush(&mut new_events);
+
+    // E. 第二遍：公式交叉引用（引用可能出现在公式之前）；
+    // 公式已被替换为占位符、引用也可能来自宏展开，所以不能按源文本判断是否需要
+    new_events = math_renderer.resolve_references(new_events);
+
+    // F. 最终渲染
     let mut html_output = String::new();
     html::push_html(&mut html_output, new_events.into_iter());
-    html_output
+    RenderResult {
+        html: html_output,
+        unresolved_labels: math_renderer.unresolved_labels(),
+        references: text_pass.into_references(),
+        missing_wikilinks: wikilinks.into_missing(),
+    }
+}
+
+// Regex 模式：把合并后的文本交给公式识别
+fn flush_pending_text(
+    pending_text: &mut String,
+    formulas: &[math::delimiters::Formula],
+    math_renderer: &MathRenderer,
+    text_pass: &mut text::TextPass,
+    events: &mut Vec<Event<'_>>,
+) {
+    let native_math = math_renderer.options.parser == MathParser::Native;
+    math::delimiters::push_text_with_placeholders(
+        CowStr::from(std::mem::take(pending_text)),
+        formulas,
+        events,
+        |formula| math_renderer.render(&formula.latex, formula.display),
+        |text, events| {
+            if native_math {
+                text_pass.push(text, events)
+            } else {
+                math::push_text_with_math(text, math_renderer, events, |text, events| {
+                    text_pass.push(text, events)
+                })
+            }
+        },
+    );
 }
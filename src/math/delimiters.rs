@@ -0,0 +1,369 @@
+// 额外的公式分隔符：`\(...\)`、`\[...\]` 与 GitLab 风格的 $`...`$，以及包含 `|`、`~`、`^` 的 `$...$`
+//
+// pulldown-cmark 会把 `\(` 当作转义字符处理、把 $`...`$ 拆成文本 + 行内代码，
+// 表格会在 `$a|b$` 的 `|` 处拆分单元格，Regex 模式下 `$a~~b$ $c~~d$` 中的 `~~`、`^` 会被当作
+// 删除线、下标与上标，等到 Text 事件时原始公式已经丢失。
+// 所以这里在解析前先扫描源文本：
+// 1. 先解析一遍，记录行内代码、代码块与 HTML 的范围，这些范围内不识别公式
+// 2. 找到的公式替换为占位符 `\u{E000}序号\u{E001}`（私有区字符，不含任何 Markdown 语法）
+// 3. 事件循环中遇到包含占位符的 Text 时，再替换为渲染好的公式
+
+use std::ops::Range;
+
+use pulldown_cmark::{CowStr, Event, Options, Parser, Tag};
+
+use crate::options::{MathDelimiters, MathOptions, MathParser};
+
+use super::dollars;
+
+const PLACEHOLDER_START: char = '\u{E000}';
+const PLACEHOLDER_END: char = '\u{E001}';
+
+pub(crate) struct Formula {
+    pub(crate) latex: String,
+    pub(crate) display: bool,
+}
+
+// 源文本中的一个公式
+struct Span {
+    range: Range<usize>,
+    formula: Formula,
+}
+
+// 行内代码：(源文本范围, 代码内容)
+type CodeSpan = (Range<usize>, String);
+
+// 不识别公式的范围：行内代码、代码块、HTML
+fn protected_ranges(source: &str, options: Options) -> (Vec<Range<usize>>, Vec<CodeSpan>) {
+    let mut protected = Vec::new();
+    let mut code_spans = Vec::new();
+
+    for (event, range) in Parser::new_ext(source, options).into_offset_iter() {
+        match event {
+            Event::Code(code) => {
+                code_spans.push((range.clone(), code.to_string()));
+                protected.push(range);
+            }
+            Event::Start(Tag::CodeBlock(_))
+            | Event::Start(Tag::HtmlBlock)
+            | Event::Html(_)
+            | Event::InlineHtml(_) => protected.push(range),
+            _ => {}
+        }
+    }
+
+    // 合并为有序且互不重叠的范围，便于二分查找
+    protected.sort_by_key(|range| range.start);
+    let mut merged: Vec<Range<usize>> = Vec::with_capacity(protected.len());
+    for range in protected {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    (merged, code_spans)
+}
+
+fn is_protected(protected: &[Range<usize>], pos: usize) -> bool {
+    let index = protected.partition_point(|range| range.start <= pos);
+    index > 0 && protected[index - 1].contains(&pos)
+}
+
+// 从 `start` 开始查找结束分隔符，不跨越空行与受保护范围
+fn find_closing(
+    source: &str,
+    start: usize,
+    closing: &str,
+    protected: &[Range<usize>],
+) -> Option<usize> {
+    let bytes = source.as_bytes();
+    let mut i = start;
+    while i < source.len() {
+        if is_protected(protected, i) {
+            return None;
+        }
+        if bytes[i..].starts_with(b"\n\n") || bytes[i..].starts_with(b"\r\n\r\n") {
+            return None;
+        }
+        if bytes[i] == b'\\' {
+            if bytes[i..].starts_with(closing.as_bytes()) {
+                return Some(i);
+            }
+            // 跳过被转义的字符
+            i += 2;
+            continue;
+        }
+        i += 1;
+    }
+    None
+}
+
+fn scan_backslash_delimiters(
+    source: &str,
+    delimiters: &MathDelimiters,
+    protected: &[Range<usize>],
+    spans: &mut Vec<Span>,
+) {
+    let bytes = source.as_bytes();
+    let mut i = 0;
+
+    while i + 1 < source.len() {
+        if bytes[i] != b'\\' || is_protected(protected, i) {
+            i += 1;
+            continue;
+        }
+
+        let (closing, display) = match bytes[i + 1] {
+            b'(' if delimiters.parens => (r"\)", false),
+            b'[' if delimiters.brackets => (r"\]", true),
+            // `\\` 或其他转义：跳过被转义的字符
+            _ => {
+                i += 2;
+                continue;
+            }
+        };
+
+        let content_start = i + 2;
+        match find_closing(source, content_start, closing, protected) {
+            Some(close) if !source[content_start..close].trim().is_empty() => {
+                spans.push(Span {
+                    range: i..close + 2,
+                    formula: Formula {
+                        latex: source[content_start..close].trim().to_string(),
+                        display,
+                    },
+                });
+                i = close + 2;
+            }
+            _ => i += 2,
+        }
+    }
+}
+
+// 包含 `markup` 中字符的 `$...$`（单行）/ `$$...$$`（不跨越空行）：提前替换为占位符，
+// 避免被表格拆分单元格或被解析成删除线、上下标
+fn scan_dollars_with_markup(
+    source: &str,
+    options: &MathOptions,
+    markup: &[char],
+    protected: &[Range<usize>],
+    spans: &mut Vec<Span>,
+) {
+    let delimiters = &options.delimiters;
+    // Regex 模式总是检查 GitHub 边界，与 `push_text_with_math` 一致
+    let github_boundaries = options.github_boundaries || options.parser == MathParser::Regex;
+    let bytes = source.as_bytes();
+    let mut i = 0;
+
+    while i < source.len() {
+        if is_protected(protected, i) {
+            i += 1;
+            continue;
+        }
+        match bytes[i] {
+            b'\\' => {
+                i += 2;
+                continue;
+            }
+            b'$' => {}
+            _ => {
+                i += 1;
+                continue;
+            }
+        }
+
+        let display = bytes[i..].starts_with(b"$$");
+        let closing = if display { "$$" } else { "$" };
+        let content_start = i + closing.len();
+        let line_end = if display {
+            paragraph_end(source, content_start)
+        } else {
+            source[content_start..]
+                .find('\n')
+                .map_or(source.len(), |end| content_start + end)
+        };
+
+        // 在同一行（`$$` 为同一段落）内查找未转义的结束分隔符
+        let mut close = None;
+        let mut j = content_start;
+        while j < line_end {
+            if bytes[j] == b'\\' {
+                j += 2;
+            } else if bytes[j..].starts_with(closing.as_bytes()) {
+                close = Some(j);
+                break;
+            } else {
+                j += 1;
+            }
+        }
+
+        let Some(close) = close.filter(|&close| !is_protected(protected, close)) else {
+            i = content_start;
+            continue;
+        };
+        let content = &source[content_start..close];
+        let accepted = if display {
+            delimiters.double_dollars && !content.trim().is_empty()
+        } else {
+            let prev = source[..i].chars().next_back();
+            let next = source[close + 1..].chars().next();
+            delimiters.dollars
+                && dollars::accepts_inline(
+                    options.dollar_rule,
+                    github_boundaries,
+                    prev,
+                    content,
+                    next,
+                )
+        };
+
+        if !accepted {
+            i = content_start;
+            continue;
+        }
+        if content.contains(markup) {
+            spans.push(Span {
+                range: i..close + closing.len(),
+                formula: Formula {
+                    latex: content.to_string(),
+                    display,
+                },
+            });
+        }
+        i = close + closing.len();
+    }
+}
+
+// 从 `from` 开始到下一个空行（或文本末尾）的位置
+fn paragraph_end(source: &str, from: usize) -> usize {
+    let mut pos = from;
+    for line in source[from..].split_inclusive('\n') {
+        if pos > from && line.trim().is_empty() {
+            return pos;
+        }
+        pos += line.len();
+    }
+    source.len()
+}
+
+/// 扫描源文本中启用的额外分隔符，返回替换为占位符后的文本与公式列表。
+/// 没有找到任何公式时返回 `None`，调用方直接使用原文
+pub(crate) fn extract(
+    source: &str,
+    math_options: &MathOptions,
+    options: Options,
+) -> Option<(String, Vec<Formula>)> {
+    let delimiters = &math_options.delimiters;
+    let wants_backslash = (delimiters.parens && source.contains(r"\("))
+        || (delimiters.brackets && source.contains(r"\["));
+    let wants_gitlab = delimiters.gitlab && source.contains("$`");
+    // Regex 模式在 Markdown 行内解析之后才查找公式，`~`、`^` 会先被解析成删除线与上下标，
+    // 引号等会先被智能标点替换
+    let mut markup = vec!['|'];
+    if math_options.parser == MathParser::Regex {
+        markup.push('~');
+        if options.contains(Options::ENABLE_SUPERSCRIPT) {
+            markup.push('^');
+        }
+        // 智能标点会改写引号、`--` 与 `...`（如 `$f'(x)$`）
+        if options.contains(Options::ENABLE_SMART_PUNCTUATION) {
+            markup.extend(['\'', '"', '-', '.']);
+        }
+    }
+    let wants_markup = (delimiters.dollars || delimiters.double_dollars)
+        && source.contains('$')
+        && source.contains(markup.as_slice());
+    if !wants_backslash && !wants_gitlab && !wants_markup {
+        return None;
+    }
+
+    // 扫描时不开启 ENABLE_MATH，$`...`$ 才会被解析成行内代码
+    let (protected, code_spans) = protected_ranges(source, options - Options::ENABLE_MATH);
+    let mut spans = Vec::new();
+
+    if wants_gitlab {
+        for (range, code) in &code_spans {
+            let before = source[..range.start].ends_with('$');
+            let after = source[range.end..].starts_with('$');
+            if before && after {
+                spans.push(Span {
+                    range: range.start - 1..range.end + 1,
+                    formula: Formula {
+                        latex: code.clone(),
+                        display: false,
+                    },
+                });
+            }
+        }
+    }
+
+    if wants_backslash {
+        scan_backslash_delimiters(source, delimiters, &protected, &mut spans);
+    }
+
+    if wants_markup {
+        scan_dollars_with_markup(source, math_options, &markup, &protected, &mut spans);
+    }
+
+    if spans.is_empty() {
+        return None;
+    }
+
+    spans.sort_by_key(|span| span.range.start);
+
+    let mut output = String::with_capacity(source.len());
+    let mut formulas = Vec::with_capacity(spans.len());
+    let mut last_end = 0;
+
+    for span in spans {
+        // 重叠的范围只保留第一个
+        if span.range.start < last_end {
+            continue;
+        }
+        output.push_str(&source[last_end..span.range.start]);
+        output.push(PLACEHOLDER_START);
+        output.push_str(&formulas.len().to_string());
+        output.push(PLACEHOLDER_END);
+        formulas.push(span.formula);
+        last_end = span.range.end;
+    }
+    output.push_str(&source[last_end..]);
+
+    Some((output, formulas))
+}
+
+/// 把 Text 中的占位符替换为渲染后的公式，其余文本交给 `push_text` 继续处理
+pub(crate) fn push_text_with_placeholders<'a>(
+    text: CowStr<'a>,
+    formulas: &[Formula],
+    events: &mut Vec<Event<'a>>,
+    render: impl Fn(&Formula) -> String,
+    mut push_text: impl FnMut(CowStr<'a>, &mut Vec<Event<'a>>),
+) {
+    if formulas.is_empty() || !text.contains(PLACEHOLDER_START) {
+        push_text(text, events);
+        return;
+    }
+
+    let mut rest: &str = &text;
+    while let Some(start) = rest.find(PLACEHOLDER_START) {
+        let after_start = start + PLACEHOLDER_START.len_utf8();
+        let Some(len) = rest[after_start..].find(PLACEHOLDER_END) else {
+            break;
+        };
+        let index = rest[after_start..after_start + len].parse::<usize>().ok();
+        let Some(formula) = index.and_then(|i| formulas.get(i)) else {
+            break;
+        };
+
+        if start > 0 {
+            push_text(CowStr::from(rest[..start].to_string()), events);
+        }
+        events.push(Event::Html(CowStr::from(render(formula))));
+        rest = &rest[after_start + len + PLACEHOLDER_END.len_utf8()..];
+    }
+
+    if !rest.is_empty() {
+        push_text(CowStr::from(rest.to_string()), events);
+    }
+}
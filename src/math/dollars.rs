@@ -0,0 +1,173 @@
+// `$` 公式的识别规则（Regex 模式的扫描器，以及 Native 模式的行内公式过滤）
+//
+// Regex 模式下相邻的 Text 事件会先合并，被 Markdown 转义的字符前面加上标记 `ESCAPE`：
+// 文本中去掉标记（与 Markdown 的结果相同），公式中标记还原为 `\`（保留 `\{`、`\,`、`\\` 等 LaTeX 源码）。
+//
+// 扫描器是一个从左到右的状态机：
+// - 文本状态：
+//   - 转义的 `\$`（`ESCAPE` + `$`）是普通美元符号
+//   - `$$` 查找下一个未转义的 `$$`，找到且内容非空时为块级公式，否则两个 `$` 都是普通文本
+//   - `$` 先检查开始条件，再查找下一个未转义的 `$` 作为结束候选；结束候选不满足条件时，
+//     开始的 `$` 当作普通文本，从它后面继续扫描（`$5 and $x$` 中的 `$x$` 仍然是公式）
+// - 公式状态：`\$`（包括转义的）属于公式内容，不会结束公式
+//
+// 单个 `$` 的开始/结束条件由 `DollarRule` 决定：
+// - Github：`$` 内侧不能是空白，外侧不能紧贴 ASCII 字母或数字（`$5/month`、`a$x$b` 不是公式）
+// - Pandoc：`$` 内侧不能是空白，结束的 `$` 后面不能紧跟数字（`$5 and $10` 不是公式）
+// - Strict：不识别单个 `$`
+
+use crate::options::{DollarRule, MathDelimiters};
+
+/// Regex 模式下合并相邻 Text 时，加在被 Markdown 转义的字符前面的私有区字符
+pub(crate) const ESCAPE: char = '\u{E002}';
+
+pub(crate) enum Segment {
+    Text(String),
+    Math { latex: String, display: bool },
+}
+
+// 判断单个 `$` 包围的公式是否成立；`prev` / `next` 是两个 `$` 外侧的字符
+pub(crate) fn accepts_inline(
+    rule: DollarRule,
+    github_boundaries: bool,
+    prev: Option<char>,
+    content: &str,
+    next: Option<char>,
+) -> bool {
+    let inner_ok = content.starts_with(|c: char| !c.is_whitespace())
+        && content.ends_with(|c: char| !c.is_whitespace());
+    match rule {
+        DollarRule::Github => {
+            inner_ok && (!github_boundaries || super::has_github_boundaries(prev, next))
+        }
+        DollarRule::Pandoc => inner_ok && next.is_none_or(|c| !c.is_ascii_digit()),
+        DollarRule::Strict => false,
+    }
+}
+
+// 从 `start` 开始查找未转义的 `closing`
+fn find_closing(text: &str, start: usize, closing: &str) -> Option<usize> {
+    let mut pos = start;
+    while pos < text.len() {
+        let rest = &text[pos..];
+        if rest.starts_with(closing) {
+            return Some(pos);
+        }
+        // `\`、转义标记与后面的字符一起跳过：`\$` 不会结束公式
+        let skip = if rest.starts_with(['\\', ESCAPE]) {
+            2
+        } else {
+            1
+        };
+        for c in rest.chars().take(skip) {
+            pos += c.len_utf8();
+        }
+    }
+    None
+}
+
+// 文本中去掉转义标记，公式中还原为 `\`
+fn restore(text: &str, replacement: &str) -> String {
+    text.replace(ESCAPE, replacement)
+}
+
+fn push_math(
+    segments: &mut Vec<Segment>,
+    text: &str,
+    last_end: &mut usize,
+    range: (usize, usize),
+    latex: &str,
+    display: bool,
+) {
+    if range.0 > *last_end {
+        segments.push(Segment::Text(restore(&text[*last_end..range.0], "")));
+    }
+    segments.push(Segment::Math {
+        latex: restore(latex, "\\"),
+        display,
+    });
+    *last_end = range.1;
+}
+
+/// 把文本切分为普通文本与公式
+pub(crate) fn scan(
+    text: &str,
+    rule: DollarRule,
+    github_boundaries: bool,
+    delimiters: &MathDelimiters,
+) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut last_end = 0;
+    let mut pos = 0;
+
+    while pos < text.len() {
+        let rest = &text[pos..];
+
+        // 转义的字符（包括 `\$`）不是分隔符
+        if rest.starts_with(ESCAPE) {
+            pos += ESCAPE.len_utf8();
+            pos += text[pos..].chars().next().map_or(0, char::len_utf8);
+            continue;
+        }
+
+        if rest.starts_with("$$") {
+            let content_start = pos + 2;
+            match find_closing(text, content_start, "$$") {
+                Some(close)
+                    if delimiters.double_dollars
+                        && !text[content_start..close].trim().is_empty() =>
+                {
+                    push_math(
+                        &mut segments,
+                        text,
+                        &mut last_end,
+                        (pos, close + 2),
+                        &text[content_start..close],
+                        true,
+                    );
+                    pos = close + 2;
+                }
+                _ => pos += 2,
+            }
+            continue;
+        }
+
+        if rest.starts_with('$') {
+            let content_start = pos + 1;
+            let prev = text[..pos].chars().next_back();
+            let accepted = find_closing(text, content_start, "$").filter(|&close| {
+                let next = text[close + 1..].chars().next();
+                delimiters.dollars
+                    && accepts_inline(
+                        rule,
+                        github_boundaries,
+                        prev,
+                        &text[content_start..close],
+                        next,
+                    )
+            });
+            match accepted {
+                Some(close) => {
+                    push_math(
+                        &mut segments,
+                        text,
+                        &mut last_end,
+                        (pos, close + 1),
+                        &text[content_start..close],
+                        false,
+                    );
+                    pos = close + 1;
+                }
+                None => pos += 1,
+            }
+            continue;
+        }
+
+        pos += rest.chars().next().unwrap().len_utf8();
+    }
+
+    if last_end < text.len() {
+        segments.push(Segment::Text(restore(&text[last_end..], "")));
+    }
+    segments
+}
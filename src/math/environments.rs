@@ -0,0 +1,373 @@
+// 数学环境：latex2mathml 不支持或渲染不正确的环境由这里直接生成 `<mtable>`
+//
+// 处理方式：
+// 1. 找到公式中的 `\begin{env}...\end{env}` 与 `\operatorname{...}`，替换为占位字符
+//    （第 16 平面私有区字符，latex2mathml 会输出为 `<mi mathvariant="normal">…</mi>`）
+// 2. 剩下的公式交给 latex2mathml 转换
+// 3. 把占位符替换为自行生成的 MathML；单元格内容递归调用本模块，可以嵌套环境
+//
+// 支持的环境见 `table_style`，不认识的环境原样交给 latex2mathml。
+
+use latex2mathml::{DisplayStyle, LatexError};
+
+use crate::escape_html;
+
+use super::macros::read_group;
+
+const PLACEHOLDER_BASE: u32 = 0x100000;
+const MAX_PLACEHOLDERS: usize = 0xFFFD;
+
+// 表格的对齐方式与两侧定界符
+struct TableStyle {
+    // 每列的对齐方式，列数超出时循环使用
+    columns: Vec<&'static str>,
+    open: Option<&'static str>,
+    close: Option<&'static str>,
+    // 单元格是否使用行间公式样式（align、gather 等）
+    display: bool,
+}
+
+fn table_style(name: &str, column_spec: Option<&str>) -> Option<TableStyle> {
+    let name = name.trim_end_matches('*');
+    let (columns, open, close, display) = match name {
+        "align" | "aligned" | "split" | "alignat" | "alignedat" | "flalign" => {
+            (vec!["right", "left"], None, None, true)
+        }
+        "gather" | "gathered" | "multline" | "equation" => (vec!["center"], None, None, true),
+        "cases" => (vec!["left", "left"], Some("{"), None, false),
+        "dcases" => (vec!["left", "left"], Some("{"), None, true),
+        "rcases" => (vec!["left", "left"], None, Some("}"), false),
+        "matrix" | "smallmatrix" => (vec!["center"], None, None, false),
+        "pmatrix" => (vec!["center"], Some("("), Some(")"), false),
+        "bmatrix" => (vec!["center"], Some("["), Some("]"), false),
+        "Bmatrix" => (vec!["center"], Some("{"), Some("}"), false),
+        "vmatrix" => (vec!["center"], Some("|"), Some("|"), false),
+        "Vmatrix" => (vec!["center"], Some("‖"), Some("‖"), false),
+        "array" | "subarray" => {
+            let columns = column_spec
+                .unwrap_or("c")
+                .chars()
+                .filter_map(|c| match c {
+                    'l' => Some("left"),
+                    'c' => Some("center"),
+                    'r' => Some("right"),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            let columns = if columns.is_empty() {
+                vec!["center"]
+            } else {
+                columns
+            };
+            (columns, None, None, false)
+        }
+        _ => return None,
+    };
+    Some(TableStyle {
+        columns,
+        open,
+        close,
+        display,
+    })
+}
+
+// 找到与 `from` 处 `\begin{...}` 配对的 `\end{...}`，返回 (内容结束位置, `\end{}` 结束位置)
+fn find_end(s: &str, from: usize) -> Option<(usize, usize)> {
+    let mut depth = 1;
+    let mut pos = from;
+    while let Some(offset) = s[pos..].find(['\\']) {
+        let start = pos + offset;
+        let rest = &s[start..];
+        if rest.starts_with(r"\begin{") {
+            depth += 1;
+            pos = start + r"\begin{".len();
+        } else if rest.starts_with(r"\end{") {
+            depth -= 1;
+            let (_, end) = read_group(s, start + r"\end".len())?;
+            if depth == 0 {
+                return Some((start, end));
+            }
+            pos = end;
+        } else {
+            // 跳过被转义的字符（如 `\\`、`\{`）
+            pos = start + 1 + rest[1..].chars().next().map_or(0, char::len_utf8);
+        }
+    }
+    None
+}
+
+// 在顶层（不在 `{}` 或嵌套环境中）按分隔符切分
+fn split_top_level<'a>(s: &'a str, separator: &str) -> Vec<&'a str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut last = 0;
+    let mut pos = 0;
+    while pos < s.len() {
+        let rest = &s[pos..];
+        if depth == 0 && rest.starts_with(separator) {
+            parts.push(&s[last..pos]);
+            pos += separator.len();
+            last = pos;
+            continue;
+        }
+        if rest.starts_with(r"\begin{") {
+            depth += 1;
+        } else if rest.starts_with(r"\end{") {
+            depth -= 1;
+        }
+        let c = rest.chars().next().unwrap();
+        match c {
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            '\\' => {
+                // 转义字符与命令名整体跳过，避免把 `\&` 当作分隔符
+                let next = rest[1..].chars().next();
+                if next.is_some_and(|n| n != '\\' || separator != r"\\") {
+                    pos += 1 + next.map_or(0, char::len_utf8);
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        pos += c.len_utf8();
+    }
+    parts.push(&s[last..]);
+    parts
+}
+
+// latex2mathml 输出去掉外层 `<math>` 标签
+fn inner_mathml(mathml: &str) -> &str {
+    let start = mathml.find('>').map_or(0, |i| i + 1);
+    let end = mathml.rfind("</math>").unwrap_or(mathml.len());
+    &mathml[start..end]
+}
+
+fn fence(symbol: &str, form: &str) -> String {
+    format!(
+        r#"<mo stretchy="true" form="{}">{}</mo>"#,
+        form,
+        escape_html(symbol)
+    )
+}
+
+// 替换占位符的 MathML 片段
+struct Fragment {
+    mathml: String,
+    // `\operatorname*`：上下标放在正上/正下方
+    limits: bool,
+}
+
+fn render_table(body: &str, style: &TableStyle) -> Result<String, LatexError> {
+    let mut rows = String::new();
+    let mut column_count = 1;
+    for row in split_top_level(body, r"\\") {
+        // 去掉行距参数 `\\[2pt]` 与横线
+        let row = row.trim_start();
+        let row = match row.strip_prefix('[') {
+            Some(rest) => rest.find(']').map_or(row, |end| &rest[end + 1..]),
+            None => row,
+        };
+        let row = row.replace(r"\hline", "");
+        if row.trim().is_empty() {
+            continue;
+        }
+
+        let cells = split_top_level(&row, "&");
+        column_count = column_count.max(cells.len());
+        rows.push_str("<mtr>");
+        for cell in cells {
+            let cell_mathml = latex_to_mathml(cell.trim(), DisplayStyle::Inline)?;
+            let content = inner_mathml(&cell_mathml);
+            if style.display {
+                rows.push_str(&format!(
+                    r#"<mtd><mstyle displaystyle="true">{}</mstyle></mtd>"#,
+                    content
+                ));
+            } else {
+                rows.push_str(&format!("<mtd>{}</mtd>", content));
+            }
+        }
+        rows.push_str("</mtr>");
+    }
+
+    // MathML 只重复最后一个对齐值，这里按列数展开（align 需要右/左交替）
+    let columns = (0..column_count.max(style.columns.len()))
+        .map(|i| style.columns[i % style.columns.len()])
+        .collect::<Vec<_>>();
+    let table = format!(
+        r#"<mtable columnalign="{}">{}</mtable>"#,
+        columns.join(" "),
+        rows
+    );
+    if style.open.is_none() && style.close.is_none() {
+        return Ok(table);
+    }
+    Ok(format!(
+        "{}{}{}",
+        style.open.map(|s| fence(s, "prefix")).unwrap_or_default(),
+        table,
+        style.close.map(|s| fence(s, "postfix")).unwrap_or_default(),
+    ))
+}
+
+// 把支持的环境与 `\operatorname` 替换为占位符，返回 (替换后的公式, 占位符对应的 MathML)
+fn extract(latex: &str) -> Result<(String, Vec<Fragment>), LatexError> {
+    let mut output = String::with_capacity(latex.len());
+    let mut fragments = Vec::new();
+    let mut pos = 0;
+
+    while let Some(offset) = latex[pos..].find('\\') {
+        let start = pos + offset;
+        let rest = &latex[start..];
+        output.push_str(&latex[pos..start]);
+
+        let fragment = if rest.starts_with(r"\begin{") {
+            extract_environment(latex, start)?
+        } else if rest.starts_with(r"\operatorname") {
+            extract_operator(latex, start)
+        } else {
+            None
+        };
+
+        match fragment {
+            Some((fragment, end)) if fragments.len() < MAX_PLACEHOLDERS => {
+                output.push(char::from_u32(PLACEHOLDER_BASE + fragments.len() as u32).unwrap());
+                fragments.push(fragment);
+                pos = end;
+            }
+            _ => {
+                // 原样保留该命令（或转义字符），继续向后查找
+                let len = 1 + rest[1..].chars().next().map_or(0, char::len_utf8);
+                output.push_str(&rest[..len]);
+                pos = start + len;
+            }
+        }
+    }
+    output.push_str(&latex[pos..]);
+    Ok((output, fragments))
+}
+
+fn extract_environment(latex: &str, start: usize) -> Result<Option<(Fragment, usize)>, LatexError> {
+    let Some((name, mut content_start)) = read_group(latex, start + r"\begin".len()) else {
+        return Ok(None);
+    };
+    // array 的列格式 `{lcr}`，alignat 的列数 `{2}`
+    let mut column_spec = None;
+    if matches!(
+        name.trim_end_matches('*'),
+        "array" | "subarray" | "alignat" | "alignedat"
+    ) && let Some((spec, end)) = read_group(latex, content_start)
+    {
+        column_spec = Some(spec);
+        content_start = end;
+    }
+
+    let Some(style) = table_style(name, column_spec) else {
+        return Ok(None);
+    };
+    let Some((content_end, end)) = find_end(latex, content_start) else {
+        return Ok(None);
+    };
+    let mathml = render_table(&latex[content_start..content_end], &style)?;
+    Ok(Some((
+        Fragment {
+            mathml,
+            limits: false,
+        },
+        end,
+    )))
+}
+
+// `\operatorname{name}` → `<mi>name</mi>`；`\operatorname*{name}` 的上下标放在正上/正下方
+fn extract_operator(latex: &str, start: usize) -> Option<(Fragment, usize)> {
+    let mut pos = start + r"\operatorname".len();
+    let limits = latex[pos..].starts_with('*');
+    if limits {
+        pos += 1;
+    }
+    if latex[pos..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let brace = pos + latex[pos..].len() - latex[pos..].trim_start().len();
+    let (name, end) = read_group(latex, brace)?;
+    // 名称中的间距命令（如 `lim\,sup`）换成细空格
+    let name = [r"\,", r"\:", r"\;", r"\ "]
+        .iter()
+        .fold(name.replace(r"\!", ""), |name, space| {
+            name.replace(space, "\u{2009}")
+        });
+    let mathml = format!("<mi>{}</mi>", escape_html(name.trim()));
+    Some((Fragment { mathml, limits }, end))
+}
+
+// 从 `from` 开始查找与已打开的 `<tag>` 配对的 `</tag>`
+fn matching_close(mathml: &str, from: usize, tag: &str) -> Option<usize> {
+    let open = format!("<{}>", tag);
+    let close = format!("</{}>", tag);
+    let mut depth = 1;
+    let mut pos = from;
+    while let Some(offset) = mathml[pos..].find('<') {
+        let start = pos + offset;
+        if mathml[start..].starts_with(&open) {
+            depth += 1;
+        } else if mathml[start..].starts_with(&close) {
+            depth -= 1;
+            if depth == 0 {
+                return Some(start);
+            }
+        }
+        pos = start + 1;
+    }
+    None
+}
+
+// 把 latex2mathml 输出中的占位符替换为对应的 MathML
+fn substitute(mathml: &str, fragments: &[Fragment]) -> String {
+    let mut output = mathml.to_string();
+    for (i, fragment) in fragments.iter().enumerate() {
+        let placeholder = char::from_u32(PLACEHOLDER_BASE + i as u32).unwrap();
+        let Some(mut start) = output.find(placeholder) else {
+            continue;
+        };
+        let mut end = start + placeholder.len_utf8();
+
+        // 去掉包裹占位符的 <mi>，用 <mrow> 保持上下标结构
+        if let Some(open) = output[..start].rfind("<mi")
+            && output[open..start].ends_with('>')
+            && output[end..].starts_with("</mi>")
+        {
+            start = open;
+            end += "</mi>".len();
+        }
+
+        // 带上下标的 `\operatorname*`：<msub>/<msup> 改为 <munder>/<mover>
+        let mut base_start = start;
+        while fragment.limits
+            && let Some((tag, replacement)) = [("msub", "munder"), ("msup", "mover")]
+                .into_iter()
+                .find(|(tag, _)| output[..base_start].ends_with(&format!("<{}>", tag)))
+            && let Some(close) = matching_close(&output, end, tag)
+        {
+            output.replace_range(close..close + tag.len() + 3, &format!("</{}>", replacement));
+            let open = base_start - tag.len() - 2;
+            output.replace_range(open..base_start, &format!("<{}>", replacement));
+            let shift = replacement.len() - tag.len();
+            start += shift;
+            end += shift;
+            base_start = open;
+        }
+
+        output.replace_range(start..end, &format!("<mrow>{}</mrow>", fragment.mathml));
+    }
+    output
+}
+
+/// 转换 LaTeX 为 MathML，支持 latex2mathml 之外的环境
+pub(crate) fn latex_to_mathml(latex: &str, style: DisplayStyle) -> Result<String, LatexError> {
+    if !latex.contains(r"\begin") && !latex.contains(r"\operatorname") {
+        return latex2mathml::latex_to_mathml(latex, style);
+    }
+    let (prepared, fragments) = extract(latex)?;
+    let mathml = latex2mathml::latex_to_mathml(&prepared, style)?;
+    Ok(substitute(&mathml, &fragments))
+}
@@ -0,0 +1,405 @@
+// 公式编号与交叉引用：`\label`、`\tag`、`\ref`、`\eqref`
+//
+// 引用可能出现在被引用公式之前，所以分两遍处理：
+// 1. 渲染公式时记录 `\label` 并分配编号；公式内的 `\ref` / `\eqref` 先替换为占位符
+//    `{\u{E003}序号\u{E004}}`（latex2mathml 输出为 `<mrow><mi>\u{E003}</mi><mn>序号</mn><mi>\u{E004}</mi></mrow>`）
+// 2. 整个事件流生成完毕后，再把占位符以及正文 Text 中的 `\ref{…}` / `\eqref{…}`
+//    替换为指向公式 id 的链接；找不到的标签记录下来供调用方报告
+
+use std::collections::HashMap;
+
+use pulldown_cmark::{CowStr, Event};
+
+use crate::escape_html;
+use crate::options::EquationNumbers;
+
+// 公式内引用的占位符：`REF_START` + 引用序号 + `REF_END`
+pub(super) const REF_START: char = '\u{E003}';
+pub(super) const REF_END: char = '\u{E004}';
+
+/// 带 id 或编号的块级公式
+#[derive(Debug, Clone)]
+pub(crate) struct Target {
+    pub(crate) id: Option<String>,
+    pub(crate) number: Option<String>,
+}
+
+// 公式内的一处引用：(标签, 是否为 \eqref)
+type MathRef = (String, bool);
+
+#[derive(Debug, Default)]
+pub(crate) struct Equations {
+    numbering: EquationNumbers,
+    counter: usize,
+    labels: HashMap<String, Target>,
+    math_refs: Vec<MathRef>,
+    unresolved: Vec<String>,
+}
+
+// 解析 `REF_START` 之后的 `序号REF_END`，序号可能被 latex2mathml 包裹为
+// `</mi><mn>序号</mn><mi …>`。返回 (序号, 到 `REF_END` 之后的长度, 是否为 MathML 形式)
+fn parse_ref(after: &str) -> Option<(usize, usize, bool)> {
+    // 占位符很短，只在开头附近查找结束字符
+    let (end, _) = after
+        .char_indices()
+        .take_while(|(i, _)| *i < 64)
+        .find(|(_, c)| *c == REF_END)?;
+    let inner = &after[..end];
+    let digits = match inner
+        .strip_prefix("</mi><mn>")
+        .and_then(|rest| rest.split_once("</mn><mi"))
+    {
+        Some((digits, attributes)) if attributes.ends_with('>') && !attributes.contains('<') => {
+            digits
+        }
+        _ => inner,
+    };
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let index = digits.parse().ok()?;
+    Some((index, end + REF_END.len_utf8(), digits.len() != inner.len()))
+}
+
+// 标签转换为 HTML id：保留字母、数字与 `-_:.`，其余替换为 `-`
+fn label_id(label: &str) -> String {
+    let slug: String = label
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || matches!(c, '-' | '_' | ':' | '.') {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    format!("eq-{}", slug)
+}
+
+// 查找 `\name{...}`（name 后可带 `*`），返回 (命令起点, 参数, 命令终点, 是否带星号)
+fn find_command<'a>(s: &'a str, name: &str, from: usize) -> Option<(usize, &'a str, usize, bool)> {
+    let pattern = format!("\\{}", name);
+    let mut search = from;
+    while let Some(offset) = s[search..].find(&pattern) {
+        let start = search + offset;
+        let mut pos = start + pattern.len();
+        // 排除 `\refx` 这类更长的命令名
+        if s[pos..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+            search = pos;
+            continue;
+        }
+        let starred = s[pos..].starts_with('*');
+        if starred {
+            pos += 1;
+        }
+        let rest = &s[pos..];
+        let trimmed = rest.trim_start();
+        pos += rest.len() - trimmed.len();
+        if let Some(inner) = trimmed.strip_prefix('{')
+            && let Some(close) = inner.find('}')
+        {
+            return Some((start, &inner[..close], pos + 1 + close + 1, starred));
+        }
+        search = pos;
+    }
+    None
+}
+
+// 删除所有 `\name{...}`，返回 (删除后的公式, 最后一个参数)
+fn take_command(latex: &str, name: &str) -> (String, Option<(String, bool)>) {
+    let mut output = String::with_capacity(latex.len());
+    let mut value = None;
+    let mut last_end = 0;
+    while let Some((start, arg, end, starred)) = find_command(latex, name, last_end) {
+        output.push_str(&latex[last_end..start]);
+        value = Some((arg.trim().to_string(), starred));
+        last_end = end;
+    }
+    output.push_str(&latex[last_end..]);
+    (output, value)
+}
+
+// 删除不带参数的控制词（如 `\nonumber`），返回是否出现过
+fn take_flag(latex: &mut String, name: &str) -> bool {
+    let pattern = format!("\\{}", name);
+    let mut found = false;
+    let mut search = 0;
+    while let Some(offset) = latex[search..].find(&pattern) {
+        let start = search + offset;
+        let end = start + pattern.len();
+        if latex[end..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+            search = end;
+            continue;
+        }
+        latex.replace_range(start..end, "");
+        found = true;
+        search = start;
+    }
+    found
+}
+
+impl Equations {
+    pub(crate) fn new(numbering: EquationNumbers) -> Self {
+        Equations {
+            numbering,
+            ..Default::default()
+        }
+    }
+
+    /// 处理块级公式的 `\label` / `\tag` / `\nonumber`，返回去掉这些命令后的公式与公式的 id、编号
+    pub(crate) fn number_display(&mut self, latex: &str) -> (String, Option<Target>) {
+        if !latex.contains('\\') {
+            return (self.replace_refs(latex), self.assign(None, None, false));
+        }
+
+        let (without_label, label) = take_command(latex, "label");
+        let (mut body, tag) = take_command(&without_label, "tag");
+        let suppressed = take_flag(&mut body, "nonumber") | take_flag(&mut body, "notag");
+
+        // `\tag{x}` 显示为 (x)，`\tag*{x}` 原样显示
+        let tag = tag.map(|(text, starred)| if starred { text } else { format!("({})", text) });
+        let target = self.assign(label.map(|(label, _)| label), tag, suppressed);
+        (self.replace_refs(&body), target)
+    }
+
+    /// 行内公式：去掉 `\label`（行内公式没有编号），替换引用
+    pub(crate) fn prepare_inline(&mut self, latex: &str) -> String {
+        if !latex.contains('\\') {
+            return latex.to_string();
+        }
+        let (body, _) = take_command(latex, "label");
+        self.replace_refs(&body)
+    }
+
+    fn assign(
+        &mut self,
+        label: Option<String>,
+        tag: Option<String>,
+        suppressed: bool,
+    ) -> Option<Target> {
+        let auto_number = !suppressed
+            && match self.numbering {
+                EquationNumbers::None => false,
+                EquationNumbers::All => true,
+                EquationNumbers::Labeled => label.is_some(),
+            };
+
+        let number = match tag {
+            Some(tag) => Some(tag),
+            None if auto_number => {
+                self.counter += 1;
+                Some(format!("({})", self.counter))
+            }
+            None => None,
+        };
+
+        // 有标签时 id 来自标签，否则自动编号的公式使用 eq-序号
+        let id = match &label {
+            Some(label) => Some(label_id(label)),
+            None if auto_number => Some(format!("eq-{}", self.counter)),
+            None => None,
+        };
+        if id.is_none() && number.is_none() {
+            return None;
+        }
+
+        let target = Target { id, number };
+        if let Some(label) = label {
+            // 重复的标签以第一次出现为准
+            self.labels.entry(label).or_insert_with(|| target.clone());
+        }
+        Some(target)
+    }
+
+    // 公式内的 `\ref{x}` / `\eqref{x}` 替换为占位符，等第二遍再解析
+    fn replace_refs(&mut self, latex: &str) -> String {
+        if !latex.contains("ref") {
+            return latex.to_string();
+        }
+        let mut output = latex.to_string();
+        for (name, parenthesized) in [("eqref", true), ("ref", false)] {
+            let mut replaced = String::with_capacity(output.len());
+            let mut last_end = 0;
+            while let Some((start, label, end, _)) = find_command(&output, name, last_end) {
+                replaced.push_str(&output[last_end..start]);
+                // 外面加一层分组，`\ref{x}^2` 的上标作用于整个占位符
+                replaced.push('{');
+                replaced.push(REF_START);
+                replaced.push_str(&self.math_refs.len().to_string());
+                replaced.push(REF_END);
+                replaced.push('}');
+                self.math_refs
+                    .push((label.trim().to_string(), parenthesized));
+                last_end = end;
+            }
+            replaced.push_str(&output[last_end..]);
+            output = replaced;
+        }
+        output
+    }
+
+    fn resolve(&mut self, label: &str) -> Option<&Target> {
+        if !self.labels.contains_key(label) {
+            if !self.unresolved.iter().any(|missing| missing == label) {
+                self.unresolved.push(label.to_string());
+            }
+            return None;
+        }
+        self.labels.get(label)
+    }
+
+    // 引用的链接文字：\eqref 带括号，\ref 不带；没有编号的公式显示标签本身
+    fn link(&mut self, label: &str, parenthesized: bool) -> Option<String> {
+        let target = self.resolve(label)?.clone();
+        let id = target.id.unwrap_or_default();
+        let number = target.number.unwrap_or_else(|| format!("({})", label));
+        let text = if parenthesized {
+            number
+        } else {
+            number
+                .strip_prefix('(')
+                .and_then(|n| n.strip_suffix(')'))
+                .map(str::to_string)
+                .unwrap_or(number)
+        };
+        Some(format!(
+            r##"<a class="math-ref" href="#{}">{}</a>"##,
+            escape_html(&id),
+            escape_html(&text)
+        ))
+    }
+
+    // 第二遍：替换公式 HTML 中的占位符；不是占位符的字符（如代码块中的图标字体字符）保持原样
+    fn resolve_math_html(&mut self, html: &str) -> String {
+        let mut output = String::with_capacity(html.len());
+        let mut rest = html;
+        while let Some(start) = rest.find(REF_START) {
+            let before = &rest[..start];
+            let after = &rest[start + REF_START.len_utf8()..];
+            let Some((index, len, in_mathml)) = parse_ref(after) else {
+                output.push_str(&rest[..start + REF_START.len_utf8()]);
+                rest = after;
+                continue;
+            };
+            let Some((label, parenthesized)) = self.math_refs.get(index).cloned() else {
+                output.push_str(&rest[..start + REF_START.len_utf8()]);
+                rest = after;
+                continue;
+            };
+            let after = &after[len..];
+
+            // 去掉 latex2mathml 包裹占位符的 <mi> 标签
+            let wrapped = before
+                .rfind("<mi")
+                .filter(|&open| before[open..].ends_with('>') && after.starts_with("</mi>"))
+                .filter(|_| in_mathml);
+            let Some(open) = wrapped else {
+                // 不在 <mi> 中（TeX 注释、Passthrough 的 data-tex）：还原为原来的 TeX 命令
+                self.resolve(&label);
+                let command = if parenthesized { "eqref" } else { "ref" };
+                let (before, after) = match (before.strip_suffix('{'), after.strip_prefix('}')) {
+                    (Some(before), Some(after)) => (before, after),
+                    _ => (before, after),
+                };
+                output.push_str(before);
+                output.push_str(&format!("\\{}{{{}}}", command, escape_html(&label)));
+                rest = after;
+                continue;
+            };
+            output.push_str(&before[..open]);
+
+            output.push_str("<mtext>");
+            match self.link(&label, parenthesized) {
+                Some(link) => output.push_str(&link),
+                None => output.push_str(&format!(
+                    r#"<span class="math-ref math-ref-missing" title="{}">??</span>"#,
+                    escape_html(&label)
+                )),
+            }
+            output.push_str("</mtext>");
+            rest = &after["</mi>".len()..];
+        }
+        output.push_str(rest);
+        output
+    }
+
+    // 第二遍：替换正文中的 `\ref{x}` / `\eqref{x}`
+    fn push_text_refs<'a>(&mut self, text: String, events: &mut Vec<Event<'a>>) {
+        let mut last_end = 0;
+        loop {
+            let next = [("eqref", true), ("ref", false)]
+                .into_iter()
+                .filter_map(|(name, parenthesized)| {
+                    find_command(&text, name, last_end)
+                        .filter(|(_, _, _, starred)| !starred)
+                        .map(|found| (found, parenthesized))
+                })
+                .min_by_key(|((start, ..), _)| *start);
+            let Some(((start, label, end, _), parenthesized)) = next else {
+                break;
+            };
+
+            if start > last_end {
+                events.push(Event::Text(CowStr::from(text[last_end..start].to_string())));
+            }
+            let label = label.trim().to_string();
+            match self.link(&label, parenthesized) {
+                Some(link) => events.push(Event::Html(CowStr::from(link))),
+                // 找不到的标签保留原文
+                None => events.push(Event::Html(CowStr::from(format!(
+                    r#"<span class="math-ref-missing">{}</span>"#,
+                    escape_html(&text[start..end])
+                )))),
+            }
+            last_end = end;
+        }
+        if last_end < text.len() {
+            events.push(Event::Text(CowStr::from(text[last_end..].to_string())));
+        }
+    }
+
+    /// 第二遍：解析事件流中的所有引用。
+    /// 正文中的 `\ref{x}` 只在开启公式编号或文档中有 `\label` 时处理，否则保持原样
+    pub(crate) fn resolve_references<'a>(&mut self, events: Vec<Event<'a>>) -> Vec<Event<'a>> {
+        let resolve_text = self.numbering != EquationNumbers::None || !self.labels.is_empty();
+        if !resolve_text && self.math_refs.is_empty() {
+            return events;
+        }
+
+        let mut output = Vec::with_capacity(events.len());
+        // 相邻的 Text 先合并，pulldown-cmark 可能在反斜杠处拆分文本
+        let mut pending_text: Option<String> = None;
+
+        for event in events {
+            match event {
+                Event::Text(text) if resolve_text => {
+                    pending_text.get_or_insert_with(String::new).push_str(&text)
+                }
+                event => {
+                    if let Some(text) = pending_text.take() {
+                        self.push_text_refs(text, &mut output);
+                    }
+                    match event {
+                        Event::Html(html) if !self.math_refs.is_empty() => {
+                            output.push(Event::Html(CowStr::from(self.resolve_math_html(&html))))
+                        }
+                        Event::InlineHtml(html) if !self.math_refs.is_empty() => output.push(
+                            Event::InlineHtml(CowStr::from(self.resolve_math_html(&html))),
+                        ),
+                        event => output.push(event),
+                    }
+                }
+            }
+        }
+        if let Some(text) = pending_text.take() {
+            self.push_text_refs(text, &mut output);
+        }
+        output
+    }
+
+    /// 被引用但没有定义的标签，按首次出现的顺序
+    pub(crate) fn unresolved(&self) -> &[String] {
+        &self.unresolved
+    }
+}
@@ -0,0 +1,292 @@
+// 自定义 LaTeX 宏
+//
+// 宏来源：
+// - 选项中提供的宏表（如共享的宏前言），参数个数由宏体中最大的 `#n` 推断
+// - 文档公式中的 `\newcommand` / `\renewcommand` / `\providecommand` / `\def`，
+//   对之后渲染的公式生效（与 KaTeX/MathJax 的全局宏行为一致）
+//
+// 展开在 LaTeX → MathML 转换之前进行；宏体中可以继续使用其他宏，
+// 递归深度超过 `MAX_DEPTH` 或展开结果过长时报错，避免 `\def\a{\a\a}` 之类的无限展开。
+
+use std::collections::HashMap;
+
+const MAX_DEPTH: usize = 32;
+const MAX_EXPANDED_LEN: usize = 64 * 1024;
+
+#[derive(Debug, Clone)]
+struct MacroDef {
+    params: usize,
+    body: String,
+}
+
+// 宏展开失败：递归过深或展开结果过长
+#[derive(Debug)]
+pub(crate) enum MacroError {
+    DepthExceeded,
+    TooLong,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct MacroTable {
+    macros: HashMap<String, MacroDef>,
+}
+
+// 宏体中最大的参数编号
+fn infer_params(body: &str) -> usize {
+    let bytes = body.as_bytes();
+    (0..bytes.len().saturating_sub(1))
+        .filter(|&i| bytes[i] == b'#' && bytes[i + 1].is_ascii_digit())
+        .map(|i| (bytes[i + 1] - b'0') as usize)
+        .max()
+        .unwrap_or(0)
+}
+
+fn skip_whitespace(s: &str, pos: usize) -> usize {
+    pos + s[pos..].len() - s[pos..].trim_start().len()
+}
+
+// 读取一个控制序列名（不含反斜杠），返回 (名称, 结束位置)
+fn read_control_sequence(s: &str, pos: usize) -> Option<(&str, usize)> {
+    let rest = s[pos..].strip_prefix('\\')?;
+    let letters = rest
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(rest.len());
+    let len = if letters > 0 {
+        letters
+    } else {
+        rest.chars().next()?.len_utf8()
+    };
+    Some((&rest[..len], pos + 1 + len))
+}
+
+/// 读取配对的 `{...}`，返回 (内容, 结束位置)；`pos` 指向 `{`
+pub(super) fn read_group(s: &str, pos: usize) -> Option<(&str, usize)> {
+    if !s[pos..].starts_with('{') {
+        return None;
+    }
+    let mut depth = 0;
+    let mut escaped = false;
+    for (i, c) in s[pos..].char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some((&s[pos + 1..pos + i], pos + i + 1));
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+// 读取 `[...]` 可选参数，返回 (内容, 结束位置)
+fn read_optional(s: &str, pos: usize) -> Option<(&str, usize)> {
+    let rest = s[pos..].strip_prefix('[')?;
+    let end = rest.find(']')?;
+    Some((&rest[..end], pos + 1 + end + 1))
+}
+
+// 读取宏的一个参数：`{...}` 分组、控制序列或单个字符
+fn read_argument(s: &str, pos: usize) -> Option<(&str, usize)> {
+    let pos = skip_whitespace(s, pos);
+    if let Some(group) = read_group(s, pos) {
+        return Some(group);
+    }
+    if let Some((_, end)) = read_control_sequence(s, pos) {
+        return Some((&s[pos..end], end));
+    }
+    let c = s[pos..].chars().next()?;
+    Some((&s[pos..pos + c.len_utf8()], pos + c.len_utf8()))
+}
+
+fn substitute(body: &str, args: &[&str]) -> String {
+    let mut output = String::with_capacity(body.len());
+    let mut chars = body.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '#'
+            && let Some(n) = chars.peek().and_then(|d| d.to_digit(10))
+        {
+            chars.next();
+            if let Some(arg) = args.get((n as usize).wrapping_sub(1)) {
+                output.push_str(arg);
+            }
+            continue;
+        }
+        output.push(c);
+    }
+    output
+}
+
+impl MacroTable {
+    /// 从选项中的宏表创建，键名可以带或不带反斜杠
+    pub(crate) fn from_options(macros: &HashMap<String, String>) -> Self {
+        let mut table = MacroTable::default();
+        for (name, body) in macros {
+            table.define(name.trim_start_matches('\\'), infer_params(body), body);
+        }
+        table
+    }
+
+    fn define(&mut self, name: &str, params: usize, body: &str) {
+        self.macros.insert(
+            name.to_string(),
+            MacroDef {
+                params: params.min(9),
+                body: body.to_string(),
+            },
+        );
+    }
+
+    // 解析 `\newcommand{\name}[n][default]{body}`（`pos` 指向命令名之后）
+    fn parse_newcommand(&mut self, s: &str, pos: usize, overwrite: bool) -> Option<usize> {
+        let mut pos = skip_whitespace(s, pos);
+        if s[pos..].starts_with('*') {
+            pos += 1;
+        }
+        pos = skip_whitespace(s, pos);
+
+        let name = match read_group(s, pos) {
+            Some((inner, end)) => {
+                pos = end;
+                inner.trim().strip_prefix('\\')?.to_string()
+            }
+            None => {
+                let (name, end) = read_control_sequence(s, pos)?;
+                pos = end;
+                name.to_string()
+            }
+        };
+
+        pos = skip_whitespace(s, pos);
+        let mut params = 0;
+        if let Some((count, end)) = read_optional(s, pos) {
+            params = count.trim().parse().ok()?;
+            pos = skip_whitespace(s, end);
+        }
+        // 可选参数的默认值：不支持可选参数，按必选参数处理
+        if let Some((_, end)) = read_optional(s, pos) {
+            pos = skip_whitespace(s, end);
+        }
+
+        let (body, end) = read_group(s, pos)?;
+        if overwrite || !self.macros.contains_key(&name) {
+            self.define(&name, params, body);
+        }
+        Some(end)
+    }
+
+    // 解析 `\def\name#1#2{body}`（`pos` 指向 `\def` 之后）
+    fn parse_def(&mut self, s: &str, pos: usize) -> Option<usize> {
+        let pos = skip_whitespace(s, pos);
+        let (name, mut pos) = read_control_sequence(s, pos)?;
+
+        let mut params = 0;
+        while let Some(rest) = s[pos..].strip_prefix('#') {
+            let digit = rest.chars().next()?.to_digit(10)?;
+            params = params.max(digit as usize);
+            pos += 2;
+        }
+
+        let (body, end) = read_group(s, skip_whitespace(s, pos))?;
+        self.define(name, params, body);
+        Some(end)
+    }
+
+    /// 记录公式中的宏定义，返回去掉定义之后的公式
+    pub(crate) fn collect_definitions(&mut self, latex: &str) -> String {
+        if !latex.contains("command") && !latex.contains("\\def") {
+            return latex.to_string();
+        }
+
+        let mut output = String::with_capacity(latex.len());
+        let mut pos = 0;
+        while pos < latex.len() {
+            let Some((name, end)) = read_control_sequence(latex, pos) else {
+                let c = latex[pos..].chars().next().unwrap();
+                output.push(c);
+                pos += c.len_utf8();
+                continue;
+            };
+
+            let parsed = match name {
+                "newcommand" | "providecommand" => {
+                    self.parse_newcommand(latex, end, name == "newcommand")
+                }
+                "renewcommand" => self.parse_newcommand(latex, end, true),
+                "def" => self.parse_def(latex, end),
+                _ => None,
+            };
+            match parsed {
+                Some(def_end) => pos = def_end,
+                None => {
+                    output.push_str(&latex[pos..end]);
+                    pos = end;
+                }
+            }
+        }
+        output
+    }
+
+    /// 展开公式中的所有宏
+    pub(crate) fn expand(&self, latex: &str) -> Result<String, MacroError> {
+        if self.macros.is_empty() {
+            return Ok(latex.to_string());
+        }
+        self.expand_at_depth(latex, 0)
+    }
+
+    fn expand_at_depth(&self, latex: &str, depth: usize) -> Result<String, MacroError> {
+        let mut output = String::with_capacity(latex.len());
+        let mut pos = 0;
+
+        while pos < latex.len() {
+            let Some((name, end)) = read_control_sequence(latex, pos) else {
+                let c = latex[pos..].chars().next().unwrap();
+                output.push(c);
+                pos += c.len_utf8();
+                continue;
+            };
+
+            let Some(def) = self.macros.get(name) else {
+                output.push_str(&latex[pos..end]);
+                pos = end;
+                continue;
+            };
+
+            if depth >= MAX_DEPTH {
+                return Err(MacroError::DepthExceeded);
+            }
+
+            let mut args = Vec::with_capacity(def.params);
+            pos = end;
+            for _ in 0..def.params {
+                match read_argument(latex, pos) {
+                    Some((arg, arg_end)) => {
+                        args.push(arg);
+                        pos = arg_end;
+                    }
+                    None => break,
+                }
+            }
+
+            let expanded = self.expand_at_depth(&substitute(&def.body, &args), depth + 1)?;
+            // 控制词后紧跟字母时补一个空格，避免 `\R x` 展开后与后续字符粘连
+            output.push_str(&expanded);
+            if def.params == 0
+                && expanded.ends_with(|c: char| c.is_ascii_alphabetic())
+                && latex[pos..].starts_with(|c: char| c.is_ascii_alphabetic())
+            {
+                output.push(' ');
+            }
+
+            if output.len() > MAX_EXPANDED_LEN {
+                return Err(MacroError::TooLong);
+            }
+        }
+        Ok(output)
+    }
+}
@@ -0,0 +1,363 @@
+// mhchem 化学式：把 `\ce{...}` 翻译为 latex2mathml 能处理的普通 LaTeX
+//
+// 支持的语法（mhchem 的常用子集）：
+// - 化学式：`H2O`、`(NH4)2SO4`、`[Cu(NH3)4]^2+`，元素符号为正体，数字为下标
+// - 电荷：`Na+`、`NO3-`、`SO4^2-`、`Fe^{3+}`
+// - 系数与水合物：`2H2O`、`1/2O2`、`CuSO4*5H2O`
+// - 同位素：`^{14}_{6}C`
+// - 状态：`(s)`、`(l)`、`(g)`、`(aq)`；气体 `^`、沉淀 `v`
+// - 箭头：`->`、`<-`、`<->`、`<=>`，以及带条件的 `->[above][below]`
+// - 化学键：`-`、`=`、`#`
+// - `$...$` 中的内容原样作为 LaTeX
+//
+// latex2mathml 不会为 `x_a^b` 生成 msubsup，所以同时有下标和电荷时输出 `{{x}_{a}}^{b}`。
+
+const STATES: &[&str] = &["s", "l", "g", "aq", "sln", "cr"];
+
+// 箭头及其 LaTeX 写法，较长的写法在前
+const ARROWS: &[(&str, &str)] = &[
+    ("<-->", r"\longleftrightarrow"),
+    ("<=>>", r"\rightleftharpoons"),
+    ("<<=>", r"\rightleftharpoons"),
+    ("<=>", r"\rightleftharpoons"),
+    ("<->", r"\longleftrightarrow"),
+    ("->", r"\longrightarrow"),
+    ("<-", r"\longleftarrow"),
+];
+
+// 化学式中的一个元素：基础部分、下标、上标（电荷）
+#[derive(Default)]
+struct Piece {
+    base: String,
+    sub: String,
+    sup: String,
+}
+
+impl Piece {
+    fn new(base: impl Into<String>) -> Self {
+        Piece {
+            base: base.into(),
+            ..Default::default()
+        }
+    }
+
+    fn to_latex(&self) -> String {
+        match (self.sub.is_empty(), self.sup.is_empty()) {
+            (true, true) => self.base.clone(),
+            (false, true) => format!("{{{}}}_{{{}}}", self.base, self.sub),
+            (true, false) => format!("{{{}}}^{{{}}}", self.base, self.sup),
+            (false, false) => format!("{{{{{}}}_{{{}}}}}^{{{}}}", self.base, self.sub, self.sup),
+        }
+    }
+}
+
+fn roman(text: &str) -> String {
+    format!(r"\mathrm{{{}}}", text)
+}
+
+// 读取配对的 `open ... close`，返回 (内容, 结束位置)；`pos` 指向开括号
+fn read_balanced(s: &str, pos: usize, open: char, close: char) -> Option<(&str, usize)> {
+    let mut depth = 0;
+    for (i, c) in s[pos..].char_indices() {
+        if c == open {
+            depth += 1;
+        } else if c == close {
+            depth -= 1;
+            if depth == 0 {
+                return Some((&s[pos + open.len_utf8()..pos + i], pos + i + 1));
+            }
+        }
+    }
+    None
+}
+
+// 读取上下标内容：`{...}`，或者数字加可选的正负号（`2-`、`+`、`14`）
+fn read_script(s: &str, pos: usize) -> (String, usize) {
+    if s[pos..].starts_with('{')
+        && let Some((inner, end)) = read_balanced(s, pos, '{', '}')
+    {
+        return (inner.to_string(), end);
+    }
+    let rest = &s[pos..];
+    let digits = rest
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(rest.len());
+    let mut end = pos + digits;
+    if s[end..].starts_with(['+', '-']) {
+        end += 1;
+    }
+    (s[pos..end].to_string(), end)
+}
+
+// 按空白切分，但不切开括号内的内容（箭头条件 `->[in water]` 中可以有空格）
+fn split_words(content: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let mut depth = 0i32;
+    let mut in_math = false;
+    let mut start = None;
+    for (i, c) in content.char_indices() {
+        match c {
+            '$' => in_math = !in_math,
+            '[' | '{' | '(' if !in_math => depth += 1,
+            ']' | '}' | ')' if !in_math => depth -= 1,
+            _ => {}
+        }
+        if c.is_whitespace() && depth <= 0 && !in_math {
+            if let Some(s) = start.take() {
+                words.push(&content[s..i]);
+            }
+        } else if start.is_none() {
+            start = Some(i);
+        }
+    }
+    if let Some(s) = start {
+        words.push(&content[s..]);
+    }
+    words
+}
+
+// 带条件的箭头：`->[above][below]`
+fn translate_arrow(word: &str) -> Option<String> {
+    let (arrow, latex) = ARROWS.iter().find(|(arrow, _)| word.starts_with(arrow))?;
+    let mut rest = &word[arrow.len()..];
+    let mut labels = Vec::new();
+    while rest.starts_with('[') {
+        let (label, end) = read_balanced(rest, 0, '[', ']')?;
+        labels.push(translate(label));
+        rest = &rest[end..];
+    }
+    if !rest.is_empty() {
+        return None;
+    }
+
+    Some(match labels.as_slice() {
+        [] => latex.to_string(),
+        [above] => format!(r"\overset{{{}}}{{{}}}", above, latex),
+        [above, below, ..] if above.is_empty() => format!(r"\underset{{{}}}{{{}}}", below, latex),
+        [above, below, ..] => format!(
+            r"\underset{{{}}}{{\overset{{{}}}{{{}}}}}",
+            below, above, latex
+        ),
+    })
+}
+
+// 翻译一个化学式（不含空白）
+fn translate_formula(word: &str) -> String {
+    let mut pieces: Vec<Piece> = Vec::new();
+    let mut pos = 0;
+    // 位于式子开头或 `*` 之后的数字是系数而不是下标
+    let mut expect_coefficient = true;
+
+    while pos < word.len() {
+        let rest = &word[pos..];
+        let c = rest.chars().next().unwrap();
+
+        // 系数：`2`、`1/2`、`0.5`
+        if expect_coefficient && c.is_ascii_digit() {
+            let len = rest
+                .find(|c: char| !c.is_ascii_digit() && c != '.' && c != '/')
+                .unwrap_or(rest.len());
+            let number = &rest[..len];
+            pos += len;
+            // 单独的数字（如 `\ce{2}`）不加间距
+            let spacing = if pos < word.len() { r"\," } else { "" };
+            let coefficient = match number.split_once('/') {
+                Some((numerator, denominator)) => {
+                    format!(r"\frac{{{}}}{{{}}}{}", numerator, denominator, spacing)
+                }
+                None => format!("{}{}", number, spacing),
+            };
+            pieces.push(Piece::new(coefficient));
+            expect_coefficient = false;
+            continue;
+        }
+        expect_coefficient = false;
+
+        match c {
+            // 元素符号：大写字母加小写字母
+            'A'..='Z' => {
+                let len = 1 + rest[1..]
+                    .find(|c: char| !c.is_ascii_lowercase())
+                    .unwrap_or(rest.len() - 1);
+                pieces.push(Piece::new(roman(&rest[..len])));
+                pos += len;
+            }
+            'a'..='z' => {
+                let len = rest
+                    .find(|c: char| !c.is_ascii_alphabetic())
+                    .unwrap_or(rest.len());
+                pieces.push(Piece::new(roman(&rest[..len])));
+                pos += len;
+            }
+            // 元素或括号之后的数字是下标
+            '0'..='9' => {
+                let len = rest
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(rest.len());
+                match pieces.last_mut() {
+                    Some(piece) if piece.sup.is_empty() => piece.sub.push_str(&rest[..len]),
+                    _ => pieces.push(Piece::new(&rest[..len])),
+                }
+                pos += len;
+            }
+            '^' | '_' => {
+                let (script, end) = read_script(word, pos + 1);
+                if pieces.is_empty() {
+                    // 同位素前缀 `^{14}_{6}C`：用空的基础部分承载上下标
+                    pieces.push(Piece::new("{}"));
+                }
+                let piece = pieces.last_mut().unwrap();
+                if c == '^' {
+                    piece.sup.push_str(&script);
+                } else {
+                    piece.sub.push_str(&script);
+                }
+                pos = end;
+            }
+            // 末尾（或括号前）的 + / - 是电荷，其余位置的 - 是单键
+            '+' | '-' if rest.len() == 1 || rest[1..].starts_with([')', ']']) => {
+                match pieces.last_mut() {
+                    Some(piece) => piece.sup.push(c),
+                    None => pieces.push(Piece::new(c.to_string())),
+                }
+                pos += 1;
+            }
+            '-' | '=' => {
+                pieces.push(Piece::new(c.to_string()));
+                pos += 1;
+            }
+            '#' => {
+                pieces.push(Piece::new(r"\equiv"));
+                pos += 1;
+            }
+            // 水合物 `CuSO4*5H2O`
+            '*' | '.' => {
+                pieces.push(Piece::new(r"\cdot"));
+                expect_coefficient = true;
+                pos += 1;
+            }
+            '(' => {
+                let state = read_balanced(word, pos, '(', ')').filter(|(inner, end)| {
+                    STATES.contains(inner)
+                        && !word[*end..].starts_with(|c: char| c.is_ascii_digit())
+                });
+                match state {
+                    Some((inner, end)) => {
+                        pieces.push(Piece::new(format!(r"\,{}", roman(&format!("({})", inner)))));
+                        pos = end;
+                    }
+                    None => {
+                        pieces.push(Piece::new("("));
+                        pos += 1;
+                    }
+                }
+            }
+            '$' => {
+                let (math, len) = match rest[1..].find('$') {
+                    Some(i) => (&rest[1..1 + i], i + 2),
+                    None => (&rest[1..], rest.len()),
+                };
+                pieces.push(Piece::new(math));
+                pos += len;
+            }
+            '{' => match read_balanced(word, pos, '{', '}') {
+                Some((inner, end)) => {
+                    pieces.push(Piece::new(roman(inner)));
+                    pos = end;
+                }
+                None => {
+                    pos += 1;
+                }
+            },
+            // 其他 LaTeX 命令原样保留
+            '\\' => {
+                let len = 1 + rest[1..]
+                    .find(|c: char| !c.is_ascii_alphabetic())
+                    .unwrap_or(rest.len() - 1);
+                let len = if len == 1 {
+                    1 + rest[1..].chars().next().map_or(0, char::len_utf8)
+                } else {
+                    len
+                };
+                pieces.push(Piece::new(&rest[..len]));
+                pos += len;
+            }
+            _ => {
+                pieces.push(Piece::new(c.to_string()));
+                pos += c.len_utf8();
+            }
+        }
+    }
+
+    pieces.iter().map(Piece::to_latex).collect()
+}
+
+// 运算符、箭头等两侧自带间距的词
+fn is_operator(word: &str) -> bool {
+    matches!(word, "+" | "=" | "^" | "v") || ARROWS.iter().any(|(arrow, _)| word.starts_with(arrow))
+}
+
+// 翻译 `\ce{...}` 的内容
+fn translate(content: &str) -> String {
+    let mut output = String::new();
+    let mut previous_is_formula = false;
+    for word in split_words(content) {
+        let translated = match word {
+            "+" => "+".to_string(),
+            "=" => "=".to_string(),
+            // 气体与沉淀
+            "^" => r"\uparrow".to_string(),
+            "v" => r"\downarrow".to_string(),
+            _ => translate_arrow(word).unwrap_or_else(|| translate_formula(word)),
+        };
+        let is_formula = !is_operator(word);
+        if !output.is_empty() {
+            // 相邻的两个化学式或文字（如箭头条件 `[in water]`）之间保留空格
+            output.push_str(if previous_is_formula && is_formula {
+                r"\;"
+            } else {
+                " "
+            });
+        }
+        output.push_str(&translated);
+        previous_is_formula = is_formula;
+    }
+    output
+}
+
+/// 展开公式中所有的 `\ce{...}`
+pub(crate) fn expand(latex: &str) -> String {
+    if !latex.contains(r"\ce") {
+        return latex.to_string();
+    }
+
+    let mut output = String::with_capacity(latex.len());
+    let mut pos = 0;
+    while let Some(offset) = latex[pos..].find(r"\ce") {
+        let start = pos + offset;
+        let after = start + r"\ce".len();
+        output.push_str(&latex[pos..start]);
+
+        let brace = after + latex[after..].len() - latex[after..].trim_start().len();
+        let group = latex[brace..]
+            .starts_with('{')
+            .then(|| read_balanced(latex, brace, '{', '}'))
+            .flatten();
+        // 排除 `\cent` 之类的其他命令
+        let is_command = !latex[after..].starts_with(|c: char| c.is_ascii_alphabetic());
+        match group {
+            Some((content, end)) if is_command => {
+                output.push('{');
+                output.push_str(&translate(content));
+                output.push('}');
+                pos = end;
+            }
+            _ => {
+                output.push_str(r"\ce");
+                pos = after;
+            }
+        }
+    }
+    output.push_str(&latex[pos..]);
+    output
+}
@@ -0,0 +1,203 @@
+// 数学公式：分隔符识别与 LaTeX → MathML 渲染
+//
+// 两种识别方式（见 `MathParser`）：
+// - Regex：在 pulldown-cmark 产出的 Text 事件上查找 `$...$` / `$$...$$`（规则见 `dollars`）
+// - Native：开启 pulldown-cmark 的 `ENABLE_MATH`，直接处理 InlineMath / DisplayMath 事件，
+//   公式内容不会再被强调、转义等 Markdown 语法拆散
+//
+// 所有公式最终都经过 `MathRenderer::render`：先处理宏、化学式（`\ce{}`）与编号，再按 `MathOutput` 输出
+// MathML（可附带 TeX 注释）或原样交给客户端渲染的 TeX。
+// 交叉引用在整个事件流生成之后由 `MathRenderer::resolve_references` 第二遍解析。
+
+use std::cell::RefCell;
+
+use latex2mathml::DisplayStyle;
+use pulldown_cmark::{CowStr, Event};
+
+use crate::escape_html;
+use crate::options::{MathAltText, MathOptions, MathOutput};
+
+pub(crate) mod delimiters;
+pub(crate) mod dollars;
+mod environments;
+mod equations;
+mod macros;
+mod mhchem;
+mod speech;
+
+use equations::Equations;
+use macros::MacroTable;
+
+fn error_span(latex: &str) -> String {
+    format!(
+        r#"<span class="math-error" style="color:red">Error: {}</span>"#,
+        latex
+    )
+}
+
+// 在 <math> 内容外包一层 <semantics>，附带 TeX 源码注释
+fn add_tex_annotation(mathml: &str, latex: &str) -> Option<String> {
+    let content_start = mathml.find('>')? + 1;
+    let content_end = mathml.rfind("</math>")?;
+    Some(format!(
+        r#"{}<semantics><mrow>{}</mrow><annotation encoding="application/x-tex">{}</annotation></semantics></math>"#,
+        &mathml[..content_start],
+        &mathml[content_start..content_end],
+        escape_html(latex.trim())
+    ))
+}
+
+// 在 <math> 开始标签上添加 alttext 与 aria-label
+fn add_alt_text(mathml: String, alt_text: &str) -> String {
+    let Some(tag_end) = mathml.find('>') else {
+        return mathml;
+    };
+    let alt_text = escape_html(alt_text);
+    format!(
+        r#"{} alttext="{}" aria-label="{}"{}"#,
+        &mathml[..tag_end],
+        alt_text,
+        alt_text,
+        &mathml[tag_end..]
+    )
+}
+
+fn render_math(latex: &str, display_mode: bool, options: &MathOptions) -> String {
+    let output = options.output;
+    let style = if display_mode {
+        DisplayStyle::Block
+    } else {
+        DisplayStyle::Inline
+    };
+
+    // Passthrough：不转换，交给客户端的 KaTeX / MathJax 渲染
+    if output == MathOutput::Passthrough {
+        let latex = escape_html(latex.trim());
+        let class = if display_mode { "display" } else { "inline" };
+        return format!(
+            r#"<span class="math {}" data-tex="{}">{}</span>"#,
+            class, latex, latex
+        );
+    }
+
+    let mathml = match environments::latex_to_mathml(latex, style) {
+        Ok(mathml) if output == MathOutput::MathmlWithTexAnnotation => {
+            add_tex_annotation(&mathml, latex).unwrap_or(mathml)
+        }
+        Ok(mathml) => mathml,
+        Err(_) => return error_span(latex),
+    };
+
+    match options.alt_text {
+        MathAltText::None => mathml,
+        MathAltText::Tex => add_alt_text(mathml, latex.trim()),
+        MathAltText::Speech => add_alt_text(mathml, &speech::speak(latex)),
+    }
+}
+
+/// 单个文档的公式渲染器：持有公式选项以及在公式之间共享的状态（文档中定义的宏、公式编号）
+pub(crate) struct MathRenderer<'o> {
+    pub(crate) options: &'o MathOptions,
+    macros: RefCell<MacroTable>,
+    equations: RefCell<Equations>,
+}
+
+impl<'o> MathRenderer<'o> {
+    pub(crate) fn new(options: &'o MathOptions) -> Self {
+        MathRenderer {
+            options,
+            macros: RefCell::new(MacroTable::from_options(&options.macros)),
+            equations: RefCell::new(Equations::new(options.equation_numbers)),
+        }
+    }
+
+    pub(crate) fn render(&self, latex: &str, display_mode: bool) -> String {
+        let mut macros = self.macros.borrow_mut();
+
+        // 记录公式中的 \newcommand / \def，只包含定义的公式不输出任何内容
+        let body = macros.collect_definitions(latex);
+        if body.len() != latex.len() && body.trim().is_empty() {
+            return String::new();
+        }
+
+        let expanded = match macros.expand(&body) {
+            Ok(expanded) => mhchem::expand(&expanded),
+            Err(_) => return error_span(latex),
+        };
+
+        let mut equations = self.equations.borrow_mut();
+        if !display_mode {
+            return render_math(&equations.prepare_inline(&expanded), false, self.options);
+        }
+
+        let (body, target) = equations.number_display(&expanded);
+        let math_html = render_math(&body, true, self.options);
+        let Some(target) = target else {
+            return math_html;
+        };
+
+        // 带编号或标签的公式：外层 span 提供锚点 id，编号放在公式右侧
+        let id = target
+            .id
+            .map(|id| format!(r#" id="{}""#, escape_html(&id)))
+            .unwrap_or_default();
+        let number = target
+            .number
+            .map(|number| {
+                format!(
+                    r#"<span class="math-equation-number">{}</span>"#,
+                    escape_html(&number)
+                )
+            })
+            .unwrap_or_default();
+        format!(
+            r#"<span class="math-equation"{}>{}{}</span>"#,
+            id, math_html, number
+        )
+    }
+
+    /// 第二遍：把事件流中的 `\ref` / `\eqref` 替换为指向公式的链接
+    pub(crate) fn resolve_references<'a>(&self, events: Vec<Event<'a>>) -> Vec<Event<'a>> {
+        self.equations.borrow_mut().resolve_references(events)
+    }
+
+    /// 被引用但没有定义的公式标签
+    pub(crate) fn unresolved_labels(&self) -> Vec<String> {
+        self.equations.borrow().unresolved().to_vec()
+    }
+}
+
+// GitHub 风格边界检查（扩展支持中文）：
+// - 前缀：字符串开头、空白字符、标点符号、或非 ASCII 字符（如中文）
+// - 后缀：字符串结尾、空白字符、标点符号、或非 ASCII 字符
+// 即 `$` 的外侧不能紧贴 ASCII 字母或数字，例如 $5/month、a$x$b 都不是公式
+pub(crate) fn has_github_boundaries(prev: Option<char>, next: Option<char>) -> bool {
+    prev.is_none_or(|c| !c.is_ascii_alphanumeric())
+        && next.is_none_or(|c| !c.is_ascii_alphanumeric())
+}
+
+/// Regex 模式：把 Text 中的 `$...$` / `$$...$$` 替换为公式，其余部分交给 `push_text`
+pub(crate) fn push_text_with_math<'a>(
+    text: CowStr<'a>,
+    renderer: &MathRenderer,
+    events: &mut Vec<Event<'a>>,
+    mut push_text: impl FnMut(CowStr<'a>, &mut Vec<Event<'a>>),
+) {
+    // 没有 $ 符号也没有转义标记，直接跳过扫描
+    if !text.contains(['$', dollars::ESCAPE]) {
+        push_text(text, events);
+        return;
+    }
+
+    let options = renderer.options;
+    // Regex 模式总是检查 GitHub 边界（`$5/month` 不是公式）
+    let segments = dollars::scan(&text, options.dollar_rule, true, &options.delimiters);
+    for segment in segments {
+        match segment {
+            dollars::Segment::Text(text) => push_text(CowStr::from(text), events),
+            dollars::Segment::Math { latex, display } => {
+                events.push(Event::Html(CowStr::from(renderer.render(&latex, display))))
+            }
+        }
+    }
+}
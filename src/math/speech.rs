@@ -0,0 +1,439 @@
+// 公式朗读文本：把常见的 LaTeX 结构转换为英文朗读文本，用于 `<math>` 的 aria-label
+//
+// 只覆盖常见结构（分数、上下标与幂、根号、求和、积分、希腊字母、关系与运算符），
+// 不认识的命令读出命令名，保证任何输入都有可读的结果。
+
+use super::equations::{REF_END, REF_START};
+
+// 希腊字母与常见符号的读法
+const SYMBOL_WORDS: &[(&str, &str)] = &[
+    ("alpha", "alpha"),
+    ("beta", "beta"),
+    ("gamma", "gamma"),
+    ("delta", "delta"),
+    ("epsilon", "epsilon"),
+    ("varepsilon", "epsilon"),
+    ("zeta", "zeta"),
+    ("eta", "eta"),
+    ("theta", "theta"),
+    ("vartheta", "theta"),
+    ("iota", "iota"),
+    ("kappa", "kappa"),
+    ("lambda", "lambda"),
+    ("mu", "mu"),
+    ("nu", "nu"),
+    ("xi", "xi"),
+    ("pi", "pi"),
+    ("rho", "rho"),
+    ("sigma", "sigma"),
+    ("tau", "tau"),
+    ("upsilon", "upsilon"),
+    ("phi", "phi"),
+    ("varphi", "phi"),
+    ("chi", "chi"),
+    ("psi", "psi"),
+    ("omega", "omega"),
+    ("Gamma", "capital gamma"),
+    ("Delta", "capital delta"),
+    ("Theta", "capital theta"),
+    ("Lambda", "capital lambda"),
+    ("Xi", "capital xi"),
+    ("Pi", "capital pi"),
+    ("Sigma", "capital sigma"),
+    ("Phi", "capital phi"),
+    ("Psi", "capital psi"),
+    ("Omega", "capital omega"),
+    ("infty", "infinity"),
+    ("partial", "partial"),
+    ("nabla", "nabla"),
+    ("hbar", "h bar"),
+    ("ell", "ell"),
+    ("emptyset", "the empty set"),
+    ("cdots", "dot dot dot"),
+    ("ldots", "dot dot dot"),
+    ("dots", "dot dot dot"),
+    // 运算符与关系符
+    ("times", "times"),
+    ("cdot", "times"),
+    ("div", "divided by"),
+    ("pm", "plus or minus"),
+    ("mp", "minus or plus"),
+    ("le", "is less than or equal to"),
+    ("leq", "is less than or equal to"),
+    ("ge", "is greater than or equal to"),
+    ("geq", "is greater than or equal to"),
+    ("ne", "is not equal to"),
+    ("neq", "is not equal to"),
+    ("approx", "is approximately equal to"),
+    ("equiv", "is equivalent to"),
+    ("sim", "is similar to"),
+    ("propto", "is proportional to"),
+    ("in", "is in"),
+    ("notin", "is not in"),
+    ("subset", "is a subset of"),
+    ("subseteq", "is a subset of or equal to"),
+    ("cup", "union"),
+    ("cap", "intersection"),
+    ("to", "to"),
+    ("rightarrow", "right arrow"),
+    ("leftarrow", "left arrow"),
+    ("Rightarrow", "implies"),
+    ("implies", "implies"),
+    ("iff", "if and only if"),
+    ("mapsto", "maps to"),
+    ("forall", "for all"),
+    ("exists", "there exists"),
+    ("neg", "not"),
+    ("land", "and"),
+    ("lor", "or"),
+    ("circ", "composed with"),
+    ("angle", "angle"),
+    ("degree", "degrees"),
+    ("prime", "prime"),
+    // 函数名
+    ("sin", "sine"),
+    ("cos", "cosine"),
+    ("tan", "tangent"),
+    ("log", "log"),
+    ("ln", "natural log"),
+    ("exp", "exp"),
+    ("max", "max"),
+    ("min", "min"),
+    ("det", "determinant"),
+    ("gcd", "gcd"),
+];
+
+// 大型运算符：下标读作 from，上标读作 to，之后接 of
+const LARGE_OPERATORS: &[(&str, &str)] = &[
+    ("sum", "the sum"),
+    ("prod", "the product"),
+    ("int", "the integral"),
+    ("iint", "the double integral"),
+    ("iiint", "the triple integral"),
+    ("oint", "the contour integral"),
+    ("lim", "the limit"),
+    ("bigcup", "the union"),
+    ("bigcap", "the intersection"),
+];
+
+// 只影响排版、朗读时忽略的命令
+const IGNORED: &[&str] = &[
+    "left",
+    "right",
+    "big",
+    "Big",
+    "bigg",
+    "Bigg",
+    "displaystyle",
+    "textstyle",
+    "quad",
+    "qquad",
+    ",",
+    ";",
+    ":",
+    "!",
+    " ",
+    "\\",
+    "limits",
+    "nolimits",
+];
+
+// 参数原样朗读的字体命令
+const FONT_COMMANDS: &[&str] = &[
+    "mathrm",
+    "mathit",
+    "mathbf",
+    "mathsf",
+    "mathtt",
+    "mathcal",
+    "mathfrak",
+    "boldsymbol",
+    "operatorname",
+    "text",
+    "textrm",
+    "textbf",
+    "textit",
+    "mbox",
+];
+
+fn char_word(c: char) -> Option<&'static str> {
+    Some(match c {
+        '+' => "plus",
+        '-' => "minus",
+        '=' => "equals",
+        '<' => "is less than",
+        '>' => "is greater than",
+        '*' => "times",
+        '/' => "divided by",
+        '(' => "open paren",
+        ')' => "close paren",
+        '[' => "open bracket",
+        ']' => "close bracket",
+        '|' => "vertical bar",
+        ',' => "comma",
+        '!' => "factorial",
+        '\'' => "prime",
+        ':' => "colon",
+        // 对齐符号不朗读
+        '&' => "",
+        _ => return None,
+    })
+}
+
+// 单个词（如 `x`、`12`）不需要结束提示
+fn is_simple(spoken: &str) -> bool {
+    !spoken.contains(' ')
+}
+
+struct Speaker<'a> {
+    source: &'a str,
+    pos: usize,
+}
+
+impl<'a> Speaker<'a> {
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek().filter(|c| c.is_whitespace()) {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    fn read_command_name(&mut self) -> &'a str {
+        let rest = &self.source[self.pos..];
+        let len = match rest.find(|c: char| !c.is_ascii_alphabetic()) {
+            Some(0) => rest.chars().next().map_or(0, char::len_utf8),
+            Some(len) => len,
+            None => rest.len(),
+        };
+        self.pos += len;
+        &rest[..len]
+    }
+
+    // 读取 `{...}` 分组的原始内容，不是分组时返回 None
+    fn read_raw_group(&mut self) -> Option<&'a str> {
+        self.skip_whitespace();
+        let rest = &self.source[self.pos..];
+        if !rest.starts_with('{') {
+            return None;
+        }
+        let mut depth = 0;
+        for (i, c) in rest.char_indices() {
+            match c {
+                '{' => depth += 1,
+                '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        self.pos += i + 1;
+                        return Some(&rest[1..i]);
+                    }
+                }
+                _ => {}
+            }
+        }
+        self.pos = self.source.len();
+        Some(&rest[1..])
+    }
+
+    // 读取一个参数（分组或单个元素）并朗读
+    fn argument(&mut self) -> String {
+        self.skip_whitespace();
+        match self.read_raw_group() {
+            Some(group) => speak(group),
+            None => self.atom().unwrap_or_default(),
+        }
+    }
+
+    // 读取一个元素（不含上下标）
+    fn atom(&mut self) -> Option<String> {
+        self.skip_whitespace();
+        let c = self.peek()?;
+        match c {
+            '{' => self.read_raw_group().map(speak),
+            // 公式内引用的占位符（见 equations.rs）
+            REF_START => {
+                let rest = &self.source[self.pos..];
+                self.pos += rest
+                    .find(REF_END)
+                    .map_or(rest.len(), |end| end + REF_END.len_utf8());
+                Some("reference".to_string())
+            }
+            '\\' => {
+                self.pos += 1;
+                let name = self.read_command_name();
+                Some(self.command(name))
+            }
+            _ if c.is_ascii_digit() || c == '.' => {
+                let rest = &self.source[self.pos..];
+                let len = rest
+                    .find(|c: char| !c.is_ascii_digit() && c != '.')
+                    .unwrap_or(rest.len());
+                self.pos += len;
+                Some(rest[..len].to_string())
+            }
+            _ => {
+                self.pos += c.len_utf8();
+                Some(char_word(c).map_or_else(|| c.to_string(), str::to_string))
+            }
+        }
+    }
+
+    fn command(&mut self, name: &str) -> String {
+        match name {
+            "frac" | "dfrac" | "tfrac" => {
+                let numerator = self.argument();
+                let denominator = self.argument();
+                if is_simple(&numerator) && is_simple(&denominator) {
+                    format!("{} over {}", numerator, denominator)
+                } else {
+                    format!(
+                        "the fraction {} over {}, end fraction",
+                        numerator, denominator
+                    )
+                }
+            }
+            "sqrt" => {
+                // `\sqrt[n]{x}`
+                self.skip_whitespace();
+                let index = if self.peek() == Some('[') {
+                    let rest = &self.source[self.pos + 1..];
+                    let end = rest.find(']').unwrap_or(rest.len());
+                    self.pos += 1 + (end + 1).min(rest.len());
+                    Some(speak(&rest[..end]))
+                } else {
+                    None
+                };
+                let radicand = self.argument();
+                let root = match index.as_deref() {
+                    None | Some("2") => "the square root".to_string(),
+                    Some("3") => "the cube root".to_string(),
+                    Some(index) => format!("the {} root", index),
+                };
+                if is_simple(&radicand) {
+                    format!("{} of {}", root, radicand)
+                } else {
+                    format!("{} of {}, end root", root, radicand)
+                }
+            }
+            "binom" => {
+                let n = self.argument();
+                let k = self.argument();
+                format!("{} choose {}", n, k)
+            }
+            "vec" => format!("vector {}", self.argument()),
+            "hat" => format!("{} hat", self.argument()),
+            "bar" | "overline" => format!("{} bar", self.argument()),
+            "tilde" => format!("{} tilde", self.argument()),
+            "dot" => format!("{} dot", self.argument()),
+            "mathbb" => {
+                let letter = self.read_raw_group().unwrap_or_default().trim().to_string();
+                match letter.as_str() {
+                    "R" => "the real numbers".to_string(),
+                    "N" => "the natural numbers".to_string(),
+                    "Z" => "the integers".to_string(),
+                    "Q" => "the rational numbers".to_string(),
+                    "C" => "the complex numbers".to_string(),
+                    _ => format!("double-struck {}", letter),
+                }
+            }
+            _ if FONT_COMMANDS.contains(&name) => match self.read_raw_group() {
+                Some(group) if name.starts_with("text") || name == "mbox" => group.to_string(),
+                Some(group) => speak(group),
+                None => String::new(),
+            },
+            _ if IGNORED.contains(&name) => String::new(),
+            "{" | "}" | "$" | "%" | "#" | "&" | "_" => name.to_string(),
+            "|" => "double vertical bar".to_string(),
+            _ => LARGE_OPERATORS
+                .iter()
+                .chain(SYMBOL_WORDS)
+                .find(|(command, _)| *command == name)
+                .map_or_else(|| name.to_string(), |(_, word)| word.to_string()),
+        }
+    }
+
+    // 一个元素连同其上下标
+    fn term(&mut self) -> Option<String> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let base = self.atom()?;
+        let is_large_operator = self.source[start..].strip_prefix('\\').is_some_and(|rest| {
+            LARGE_OPERATORS.iter().any(|(command, _)| {
+                rest.starts_with(command)
+                    && !rest[command.len()..].starts_with(|c: char| c.is_ascii_alphabetic())
+            })
+        });
+
+        let mut words = vec![base];
+        let mut has_limits = false;
+        loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some('_') => {
+                    self.pos += 1;
+                    let sub = self.argument();
+                    if is_large_operator {
+                        has_limits = true;
+                        let word = if words[0] == "the limit" {
+                            "as"
+                        } else {
+                            "from"
+                        };
+                        words.push(format!("{} {}", word, sub));
+                    } else {
+                        words.push(format!("sub {}", sub));
+                    }
+                }
+                Some('^') => {
+                    self.pos += 1;
+                    let sup = self.argument();
+                    words.push(if is_large_operator {
+                        has_limits = true;
+                        format!("to {}", sup)
+                    } else {
+                        match sup.as_str() {
+                            "2" => "squared".to_string(),
+                            "3" => "cubed".to_string(),
+                            "prime" => "prime".to_string(),
+                            _ if is_simple(&sup) => format!("to the power of {}", sup),
+                            _ => format!("to the power of {}, end power", sup),
+                        }
+                    });
+                }
+                _ => break,
+            }
+        }
+        if is_large_operator && (has_limits || words[0] != "the limit") {
+            words.push("of".to_string());
+        }
+        Some(words.join(" "))
+    }
+}
+
+/// 把 LaTeX 转换为英文朗读文本
+pub(crate) fn speak(latex: &str) -> String {
+    let mut speaker = Speaker {
+        source: latex,
+        pos: 0,
+    };
+    let mut words = Vec::new();
+    while speaker.pos < latex.len() {
+        let before = speaker.pos;
+        match speaker.term() {
+            Some(word) if !word.is_empty() => words.push(word),
+            Some(_) => {}
+            None => break,
+        }
+        if speaker.pos == before {
+            // 无法前进（如孤立的 `}` 或 `^`），跳过一个字符
+            speaker.pos += latex[before..].chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    words
+        .join(" ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
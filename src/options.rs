@@ -0,0 +1,248 @@
+// 渲染选项
+//
+// Rust 侧直接构造 `RenderOptions`；JS 侧通过 JSON 传入（字段名使用 camelCase），
+// 未提供的字段使用默认值，默认值与 `render_markdown` 的行为保持一致。
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+/// `render_markdown_with_options` 的配置项
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct RenderOptions {
+    /// 将代码块包裹在 `<div class="awsm-code-block">` 容器中，
+    /// 附带语言/标题头部以及未高亮的原始代码（`data-code` 属性），便于实现复制按钮
+    pub code_block_container: bool,
+    /// 为标题添加 GitHub 风格的 `id`（slug 取自源文本，公式使用 LaTeX 源码）
+    pub heading_ids: bool,
+    /// 识别标题末尾的 `{#id .class key=value}` 属性块；显式 id 优先于自动生成的 slug
+    pub heading_attributes: bool,
+    /// 定义列表（`Term` 下一行 `: 定义`）
+    pub definition_lists: bool,
+    /// 上标 `^sup^`
+    pub superscript: bool,
+    /// 下标 `~sub~`（代替 GFM 的单 `~` 删除线，`~~删除线~~` 不受影响）
+    pub subscript: bool,
+    /// 智能标点：`"…"` / `'…'` 转为弯引号，`--` / `---` 转为短划线 / 长划线，`...` 转为省略号
+    pub smart_punctuation: bool,
+    /// 智能引号使用的语言区域：`en`（默认，“”）、`de`（„“）、`fr`（« »，内侧为窄不换行空格）、`ja` / `zh`（「」）
+    pub locale: String,
+    /// Obsidian 风格的 Wiki 链接 `[[Page]]`、`[[Page|标签]]` 与 `![[image.png]]`，
+    /// 页面名由 `render_markdown_with_resolver` 的解析器转换为链接
+    pub wikilinks: bool,
+    /// 缩写：`*[HTML]: Hyper Text Markup Language` 定义行不输出，文本中的整词 `HTML` 输出为 `<abbr title="...">`
+    pub abbreviations: bool,
+    /// 自定义 `::: name` 容器，按名称注册；同名时覆盖内置的 tip / warning / danger / details
+    pub containers: HashMap<String, Container>,
+    /// `@user`、`#123`、`org/repo#123` 与提交 SHA 的链接模板
+    pub references: ReferenceOptions,
+    /// `:tada:` 等 emoji 短代码
+    pub emoji: EmojiOptions,
+    /// 数学公式相关选项
+    pub math: MathOptions,
+}
+
+/// 数学公式的识别方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MathParser {
+    /// 在文本事件上用正则查找 `$...$` / `$$...$$`（默认，兼容旧版本行为）
+    #[default]
+    Regex,
+    /// 使用 pulldown-cmark 的 `ENABLE_MATH` 解析，公式内容不受强调、转义等语法影响
+    Native,
+}
+
+/// 公式的输出格式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MathOutput {
+    /// 转换为 MathML（默认）
+    #[default]
+    Mathml,
+    /// MathML 外加 `<semantics>` 与 `<annotation encoding="application/x-tex">` TeX 源码
+    MathmlWithTexAnnotation,
+    /// 不转换，输出 `<span class="math inline|display" data-tex="...">`，由客户端 KaTeX / MathJax 渲染
+    Passthrough,
+}
+
+/// `<math>` 元素的无障碍文本（`alttext` 与 `aria-label` 属性）
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MathAltText {
+    /// 不添加（默认）
+    #[default]
+    None,
+    /// 使用公式的 LaTeX 源码
+    Tex,
+    /// 使用内置规则生成的英文朗读文本，如 `\frac{a}{b}` 读作 "a over b"
+    Speech,
+}
+
+/// 块级公式的自动编号方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EquationNumbers {
+    /// 不自动编号，只显示 `\tag{...}` 指定的编号（默认）
+    #[default]
+    None,
+    /// 所有块级公式依次编号，`\nonumber` / `\notag` 可以跳过
+    All,
+    /// 只给带 `\label{...}` 的块级公式编号
+    Labeled,
+}
+
+/// 单个 `$` 的行内公式识别规则（转义的 `\$` 在任何规则下都是普通美元符号）
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DollarRule {
+    /// 开始的 `$` 后面、结束的 `$` 前面不能是空白，外侧不能紧贴 ASCII 字母或数字（默认）
+    #[default]
+    Github,
+    /// pandoc 规则：开始的 `$` 后面、结束的 `$` 前面不能是空白，结束的 `$` 后面不能紧跟数字
+    Pandoc,
+    /// 不识别单个 `$`，只接受 `$$...$$` 与 ```` ```math ```` 代码块，适合价格较多的文档
+    Strict,
+}
+
+/// 数学公式选项
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct MathOptions {
+    pub parser: MathParser,
+    /// Native 模式下对行内 `$...$` 应用 GitHub 风格边界规则：
+    /// `$` 外侧不能紧贴 ASCII 字母或数字（`a$x$b`、`$x$5` 不是公式）
+    pub github_boundaries: bool,
+    /// 单个 `$` 的识别规则
+    pub dollar_rule: DollarRule,
+    /// 启用的公式分隔符
+    pub delimiters: MathDelimiters,
+    /// 自定义宏表，例如 `{"\\R": "\\mathbb{R}"}`，参数写作 `#1`、`#2`。
+    /// 文档公式中的 `\newcommand` / `\def` 也会加入宏表，对之后的公式生效
+    pub macros: HashMap<String, String>,
+    /// 块级公式编号；`\ref` / `\eqref` 引用会链接到对应公式
+    pub equation_numbers: EquationNumbers,
+    /// 公式输出格式
+    pub output: MathOutput,
+    /// 为 `<math>` 添加 `alttext` / `aria-label`，供屏幕阅读器朗读（Passthrough 输出不适用）
+    pub alt_text: MathAltText,
+}
+
+impl Default for MathOptions {
+    fn default() -> Self {
+        MathOptions {
+            parser: MathParser::Regex,
+            github_boundaries: true,
+            dollar_rule: DollarRule::Github,
+            delimiters: MathDelimiters::default(),
+            macros: HashMap::new(),
+            equation_numbers: EquationNumbers::None,
+            output: MathOutput::Mathml,
+            alt_text: MathAltText::None,
+        }
+    }
+}
+
+/// 公式分隔符开关，每种分隔符可以单独启用
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct MathDelimiters {
+    /// `$...$` 行内公式（默认开启）
+    pub dollars: bool,
+    /// `$$...$$` 块级公式（默认开启）
+    pub double_dollars: bool,
+    /// `\(...\)` 行内公式（MathJax 风格，默认关闭）
+    pub parens: bool,
+    /// `\[...\]` 块级公式（MathJax 风格，默认关闭）
+    pub brackets: bool,
+    /// $`...`$ 行内公式（GitLab 风格，默认关闭）
+    pub gitlab: bool,
+}
+
+impl Default for MathDelimiters {
+    fn default() -> Self {
+        MathDelimiters {
+            dollars: true,
+            double_dollars: true,
+            parens: false,
+            brackets: false,
+            gitlab: false,
+        }
+    }
+}
+
+/// 引用链接模板，模板为 `None` 时不识别对应的引用
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ReferenceOptions {
+    /// `@user` 的链接，`{user}` 替换为用户名，例如 `https://github.com/{user}`
+    pub user_url: Option<String>,
+    /// `#123` / `org/repo#123` 的链接，`{repo}` 替换为仓库、`{number}` 替换为编号，
+    /// 例如 `https://github.com/{repo}/issues/{number}`
+    pub issue_url: Option<String>,
+    /// 提交 SHA 的链接，`{repo}` 替换为 `repository`、`{sha}` 替换为完整 SHA
+    pub commit_url: Option<String>,
+    /// 当前仓库（`org/repo`），用于 `#123` 与 SHA 链接中的 `{repo}`
+    pub repository: String,
+}
+
+/// Emoji 短代码选项
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct EmojiOptions {
+    /// 把文本中的 GitHub（gemoji）短代码替换为 emoji，代码、公式与链接中的文本不受影响
+    pub enabled: bool,
+    /// 输出 `<img class="emoji">` 而不是 Unicode emoji，`{name}` 替换为短代码、
+    /// `{unicode}` 替换为十六进制码位，例如 `https://cdn.example.com/emoji/{unicode}.png`
+    pub image_url: Option<String>,
+}
+
+/// `::: name 标题` 容器的输出方式
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Container {
+    /// 外层元素名（默认 `div`）；`details` 的标题输出为 `<summary>`
+    pub element: String,
+    /// 外层元素的 class，为空时使用 `custom-container <name>`
+    pub class: String,
+    /// 容器标记行没有写标题时使用的默认标题，`None` 表示不输出标题
+    pub title: Option<String>,
+}
+
+impl Default for Container {
+    fn default() -> Self {
+        Container {
+            element: "div".to_string(),
+            class: String::new(),
+            title: None,
+        }
+    }
+}
+
+impl Container {
+    /// 指定外层元素与 class 的容器
+    pub fn new(element: &str, class: &str) -> Self {
+        Container {
+            element: element.to_string(),
+            class: class.to_string(),
+            title: None,
+        }
+    }
+}
+
+impl RenderOptions {
+    /// 注册 `::: name` 容器
+    pub fn register_container(&mut self, name: &str, container: Container) -> &mut Self {
+        self.containers.insert(name.to_string(), container);
+        self
+    }
+
+    /// 从 JSON 字符串解析选项（供 wasm 导出函数使用）
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        if json.trim().is_empty() {
+            return Ok(Self::default());
+        }
+        serde_json::from_str(json)
+    }
+}
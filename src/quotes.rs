@@ -0,0 +1,62 @@
+// 按语言区域替换智能引号
+//
+// `ENABLE_SMART_PUNCTUATION` 只会生成英文引号（“” ‘’），每个引号是一个单独的 Text 事件，
+// 源文本为 `"` 或 `'`。这里把这些事件替换为对应语言的引号：
+// - en（默认）：“…” ‘…’
+// - de：„…“ ‚…‘
+// - fr：« … » ‹ … ›（引号内侧为窄不换行空格 U+202F）
+// - ja / zh：「…」 『…』
+// 源文本中直接写出的弯引号保持原样；代码与公式不会产生这类事件。
+
+// (双引号开, 双引号闭, 单引号开, 单引号闭)
+type QuoteMarks = (&'static str, &'static str, &'static str, &'static str);
+
+// 只取语言部分（`de-CH` → `de`），未知语言使用英文引号（不需要替换）
+fn quote_marks(locale: &str) -> Option<QuoteMarks> {
+    let language = locale.split(['-', '_']).next().unwrap_or_default();
+    match language.to_ascii_lowercase().as_str() {
+        "de" => Some(("„", "“", "‚", "‘")),
+        "fr" => Some(("«\u{202F}", "\u{202F}»", "‹\u{202F}", "\u{202F}›")),
+        "ja" | "zh" => Some(("「", "」", "『", "』")),
+        _ => None,
+    }
+}
+
+/// 文档内的引号替换状态
+pub(crate) struct LocaleQuotes {
+    marks: QuoteMarks,
+    // 尚未闭合的单引号数量；`’` 没有对应的开引号时是撇号（`it’s`），保持原样
+    open_singles: usize,
+}
+
+impl LocaleQuotes {
+    pub(crate) fn new(locale: &str) -> Option<Self> {
+        quote_marks(locale).map(|marks| LocaleQuotes {
+            marks,
+            open_singles: 0,
+        })
+    }
+
+    /// 新的段落（或其他文本块）开始时重置单引号配对
+    pub(crate) fn reset(&mut self) {
+        self.open_singles = 0;
+    }
+
+    /// `text` 为智能标点生成的文本，`source` 为对应的源文本；不是引号时返回 `None`
+    pub(crate) fn replace(&mut self, text: &str, source: &str) -> Option<&'static str> {
+        let (double_open, double_close, single_open, single_close) = self.marks;
+        match (source, text) {
+            ("\"", "“") => Some(double_open),
+            ("\"", "”") => Some(double_close),
+            ("'", "‘") => {
+                self.open_singles += 1;
+                Some(single_open)
+            }
+            ("'", "’") if self.open_singles > 0 => {
+                self.open_singles -= 1;
+                Some(single_close)
+            }
+            _ => None,
+        }
+    }
+}
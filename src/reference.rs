@@ -0,0 +1,179 @@
+// 引用链接：`@user`、`#123`、`org/repo#123` 与提交 SHA
+//
+// 每种引用使用各自的链接模板（`ReferenceOptions`），模板为空时不识别该种引用：
+// - `@user`：用户名为字母数字与单个 `-`（不在首尾），最长 39 个字符；`{user}`
+// - `#123` / `org/repo#123`：`{repo}`（`#123` 使用 `repository`）、`{number}`
+// - 7–40 位小写十六进制 SHA（同时包含数字与字母，避免匹配 `1234567`、`defaced`）：`{repo}`、`{sha}`
+// 引用前后不能紧贴字母数字，`a@b`、`C#1`、`#12ab` 等不是引用。
+// 输出与 github.com 相同的 class：`user-mention`、`issue-link`、`commit-link`。
+
+use std::ops::Range;
+
+use crate::escape_html;
+use crate::options::ReferenceOptions;
+use crate::result::Reference;
+
+pub(crate) struct ReferenceMatch {
+    pub(crate) range: Range<usize>,
+    pub(crate) html: String,
+    pub(crate) reference: Reference,
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+// 引用开头之前的字符：不能是单词字符，也不能是邮箱、路径或实体的一部分
+fn is_boundary(prev: Option<char>) -> bool {
+    prev.is_none_or(|c| !is_word_char(c) && !matches!(c, '.' | '-' | '+' | '@' | '/' | '&' | '#'))
+}
+
+fn fill_template(template: &str, values: &[(&str, &str)]) -> String {
+    values
+        .iter()
+        .fold(template.to_string(), |url, (name, value)| {
+            url.replace(&format!("{{{}}}", name), value)
+        })
+}
+
+fn link(href: &str, class: &str, text: &str) -> String {
+    format!(
+        "<a href=\"{}\" class=\"{}\">{}</a>",
+        escape_html(href),
+        class,
+        text
+    )
+}
+
+// 从 `start` 开始的一串满足 `accept` 的字符的结尾
+fn run_end(text: &str, start: usize, accept: impl Fn(char) -> bool) -> usize {
+    text[start..]
+        .find(|c: char| !accept(c))
+        .map_or(text.len(), |len| start + len)
+}
+
+fn match_user(text: &str, start: usize, options: &ReferenceOptions) -> Option<ReferenceMatch> {
+    let template = options.user_url.as_deref()?;
+    let name_start = start + 1;
+    let end = run_end(text, name_start, |c| c.is_ascii_alphanumeric() || c == '-');
+    let name = &text[name_start..end];
+    let valid = !name.is_empty()
+        && name.len() <= 39
+        && !name.starts_with('-')
+        && !name.ends_with('-')
+        && !name.contains("--");
+    if !valid || text[end..].starts_with(['_', '@', '/']) {
+        return None;
+    }
+
+    let href = fill_template(template, &[("user", name)]);
+    Some(ReferenceMatch {
+        range: start..end,
+        html: link(&href, "user-mention", &format!("@{}", name)),
+        reference: Reference::User {
+            name: name.to_string(),
+        },
+    })
+}
+
+// `#123` 或 `org/repo#123`，`repo_range` 为 `org/repo` 的范围
+fn match_issue(
+    text: &str,
+    start: usize,
+    repo_range: Option<Range<usize>>,
+    options: &ReferenceOptions,
+) -> Option<ReferenceMatch> {
+    let template = options.issue_url.as_deref()?;
+    let hash = repo_range.as_ref().map_or(start, |range| range.end);
+    if !text[hash..].starts_with('#') {
+        return None;
+    }
+    let end = run_end(text, hash + 1, |c| c.is_ascii_digit());
+    if end == hash + 1 || text[end..].starts_with(is_word_char) {
+        return None;
+    }
+    let number: u64 = text[hash + 1..end].parse().ok()?;
+
+    let repository = match &repo_range {
+        Some(range) => Some(&text[range.clone()]),
+        None => Some(options.repository.as_str()).filter(|repo| !repo.is_empty()),
+    };
+    let href = fill_template(
+        template,
+        &[
+            ("repo", repository.unwrap_or_default()),
+            ("number", &number.to_string()),
+        ],
+    );
+    Some(ReferenceMatch {
+        range: start..end,
+        html: link(&href, "issue-link", &escape_html(&text[start..end])),
+        reference: Reference::Issue {
+            repository: repository.map(str::to_string),
+            number,
+        },
+    })
+}
+
+// `org/repo`：组织名为字母数字与 `-`，仓库名还可以包含 `_` 与 `.`
+fn match_repo(text: &str, start: usize) -> Option<Range<usize>> {
+    let owner_end = run_end(text, start, |c| c.is_ascii_alphanumeric() || c == '-');
+    if owner_end == start || !text[owner_end..].starts_with('/') {
+        return None;
+    }
+    let repo_end = run_end(text, owner_end + 1, |c| {
+        c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')
+    });
+    (repo_end > owner_end + 1).then_some(start..repo_end)
+}
+
+fn match_commit(text: &str, start: usize, options: &ReferenceOptions) -> Option<ReferenceMatch> {
+    let template = options.commit_url.as_deref()?;
+    let end = run_end(text, start, is_word_char);
+    let sha = &text[start..end];
+    let valid = (7..=40).contains(&sha.len())
+        && sha.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f'))
+        && sha.bytes().any(|b| b.is_ascii_digit())
+        && sha.bytes().any(|b| b.is_ascii_alphabetic());
+    if !valid {
+        return None;
+    }
+
+    let href = fill_template(template, &[("repo", &options.repository), ("sha", sha)]);
+    Some(ReferenceMatch {
+        range: start..end,
+        html: link(&href, "commit-link", &format!("<code>{}</code>", &sha[..7])),
+        reference: Reference::Commit {
+            sha: sha.to_string(),
+        },
+    })
+}
+
+/// 查找文本中的引用，按出现顺序返回
+pub(crate) fn find(text: &str, options: &ReferenceOptions) -> Vec<ReferenceMatch> {
+    let mut matches = Vec::new();
+    if options.user_url.is_none() && options.issue_url.is_none() && options.commit_url.is_none() {
+        return matches;
+    }
+
+    let mut prev = None;
+    let mut skip_to = 0;
+    for (i, c) in text.char_indices() {
+        if i >= skip_to && is_boundary(prev) {
+            let found = match c {
+                '@' => match_user(text, i, options),
+                '#' => match_issue(text, i, None, options),
+                _ if c.is_ascii_alphanumeric() => match_repo(text, i)
+                    .and_then(|repo| match_issue(text, i, Some(repo), options))
+                    .or_else(|| match_commit(text, i, options)),
+                _ => None,
+            };
+            if let Some(found) = found {
+                skip_to = found.range.end;
+                matches.push(found);
+            }
+        }
+        prev = Some(c);
+    }
+    matches
+}
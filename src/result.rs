@@ -0,0 +1,35 @@
+// 渲染结果元数据
+//
+// `render_markdown_with_metadata` 除 HTML 外还返回渲染过程中收集到的信息，
+// JS 侧以 JSON 对象返回（字段名使用 camelCase）。
+
+use serde::Serialize;
+
+/// `render_markdown_with_metadata` 的返回值
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenderResult {
+    /// 渲染后的 HTML，与 `render_markdown_with_options` 的返回值相同
+    pub html: String,
+    /// `\ref` / `\eqref` 引用了但文档中没有定义的公式标签，按首次出现的顺序
+    pub unresolved_labels: Vec<String>,
+    /// 文档中的 `@user`、`#123` / `org/repo#123` 与提交 SHA 引用，按首次出现的顺序去重
+    pub references: Vec<Reference>,
+    /// Wiki 链接（`[[Page]]`、`![[image.png]]`）指向的不存在的页面，按首次出现的顺序去重
+    pub missing_wikilinks: Vec<String>,
+}
+
+/// 识别出的一个引用（只有配置了对应链接模板的引用才会被识别）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Reference {
+    /// `@user`
+    User { name: String },
+    /// `#123`（仓库为 `ReferenceOptions::repository`，未设置时为 `None`）或 `org/repo#123`
+    Issue {
+        repository: Option<String>,
+        number: u64,
+    },
+    /// 提交 SHA（完整的 7–40 位）
+    Commit { sha: String },
+}
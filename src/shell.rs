@@ -0,0 +1,86 @@
+// Shell 会话代码块（```console / ```shell-session）
+//
+// 规则：
+// - 以 `$ ` 或 `# ` 开头的行是命令：提示符单独放入不可选中的 span，
+//   命令部分使用 bash 语法高亮
+// - 以 `\` 结尾的命令行会延续到下一行
+// - 其余行是命令输出：不做语法高亮，仅转义（包含 ANSI 序列时按终端输出渲染）
+
+use syntect::html::{ClassStyle, ClassedHTMLGenerator};
+use syntect::util::LinesWithEndings;
+
+use crate::{SYNTAX_SET, ansi, escape_html};
+
+const PROMPTS: [&str; 2] = ["$ ", "# "];
+
+fn split_prompt(line: &str) -> Option<(&str, &str)> {
+    PROMPTS
+        .iter()
+        .find(|prompt| line.starts_with(*prompt))
+        .map(|prompt| line.split_at(prompt.len()))
+}
+
+fn highlight_command(command: &str) -> String {
+    let ss = &SYNTAX_SET;
+    let syntax = ss
+        .find_syntax_by_token("bash")
+        .unwrap_or_else(|| ss.find_syntax_plain_text());
+
+    let mut html_generator =
+        ClassedHTMLGenerator::new_with_class_style(syntax, ss, ClassStyle::Spaced);
+    for line in LinesWithEndings::from(command) {
+        let _ = html_generator.parse_html_for_line_which_includes_newline(line);
+    }
+    html_generator.finalize()
+}
+
+fn render_output(output: &str) -> String {
+    if ansi::contains_ansi(output) {
+        ansi::render_ansi(output)
+    } else {
+        escape_html(output)
+    }
+}
+
+// 去掉行尾换行符，返回 (内容, 换行符)
+fn split_newline(line: &str) -> (&str, &str) {
+    let content = line.trim_end_matches(['\n', '\r']);
+    (content, &line[content.len()..])
+}
+
+/// 渲染 Shell 会话内容（已转义，不含外层 <pre>）
+pub(crate) fn render_shell_session(code: &str) -> String {
+    let mut output = String::with_capacity(code.len() * 2);
+    let mut lines = LinesWithEndings::from(code).peekable();
+
+    while let Some(line) = lines.next() {
+        let Some((prompt, first)) = split_prompt(line) else {
+            let (content, newline) = split_newline(line);
+            output.push_str(&format!(
+                r#"<span class="shell-output">{}</span>{}"#,
+                render_output(content),
+                newline
+            ));
+            continue;
+        };
+
+        // 收集带续行符的完整命令
+        let mut command = first.to_string();
+        while split_newline(&command).0.ends_with('\\') {
+            match lines.next() {
+                Some(next) => command.push_str(next),
+                None => break,
+            }
+        }
+
+        let (command, newline) = split_newline(&command);
+        output.push_str(&format!(
+            r#"<span class="shell-prompt" aria-hidden="true">{}</span><span class="shell-command">{}</span>{}"#,
+            escape_html(prompt),
+            highlight_command(command),
+            newline
+        ));
+    }
+
+    output
+}
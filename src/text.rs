@@ -0,0 +1,179 @@
+// 普通文本的后处理：GFM 自动链接、引用链接、emoji 短代码、缩写
+//
+// 相邻的 Text 事件合并、公式替换之后，剩下的普通文本片段都经过 `TextPass::push`。
+// 代码、公式与 HTML 不是 Text 事件，不会到达这里；链接与图片中的文本保持原样。
+// 各个处理依次进行，前一步没有替换的文本片段才交给下一步（自动链接中的 `@`、`#` 不会再变成引用）。
+
+use std::ops::Range;
+
+use pulldown_cmark::{CowStr, Event, LinkType, Tag, TagEnd};
+
+use crate::abbreviation::{self, Abbreviation};
+use crate::options::{EmojiOptions, ReferenceOptions};
+use crate::result::Reference;
+use crate::{autolink, emoji, reference};
+
+// 文本处理的步骤，按顺序进行
+#[derive(Clone, Copy)]
+enum Stage {
+    Autolink,
+    Reference,
+    Emoji,
+    Abbreviation,
+    Done,
+}
+
+impl Stage {
+    fn next(self) -> Stage {
+        match self {
+            Stage::Autolink => Stage::Reference,
+            Stage::Reference => Stage::Emoji,
+            Stage::Emoji => Stage::Abbreviation,
+            Stage::Abbreviation | Stage::Done => Stage::Done,
+        }
+    }
+}
+
+// 文本中被替换的一段
+enum Replacement {
+    // 由 pulldown-cmark 输出的 `<a href>`
+    Link { href: String, text: String },
+    Text(String),
+    Html(String),
+}
+
+pub(crate) struct TextPass<'o> {
+    references: &'o ReferenceOptions,
+    emoji: &'o EmojiOptions,
+    abbreviations: &'o [Abbreviation],
+    // 当前所在的链接 / 图片层数（包括行内 HTML 的 `<a>`）
+    link_depth: usize,
+    found_references: Vec<Reference>,
+}
+
+// 行内 HTML 是否为 `<a ...>` / `</a>`：(是开始标签, 是结束标签)
+fn anchor_tag(html: &str) -> (bool, bool) {
+    let tag = html.trim_start().to_ascii_lowercase();
+    let is_name_end = |rest: &str| rest.starts_with(|c: char| c == '>' || c.is_whitespace());
+    (
+        tag.strip_prefix("<a").is_some_and(is_name_end),
+        tag.strip_prefix("</a").is_some_and(is_name_end),
+    )
+}
+
+impl<'o> TextPass<'o> {
+    pub(crate) fn new(
+        references: &'o ReferenceOptions,
+        emoji: &'o EmojiOptions,
+        abbreviations: &'o [Abbreviation],
+    ) -> Self {
+        TextPass {
+            references,
+            emoji,
+            abbreviations,
+            link_depth: 0,
+            found_references: Vec::new(),
+        }
+    }
+
+    /// 文档中找到的引用，按首次出现的顺序去重
+    pub(crate) fn into_references(self) -> Vec<Reference> {
+        self.found_references
+    }
+
+    /// 记录链接的开始与结束；缓存的文本需要在这之前处理完
+    pub(crate) fn observe(&mut self, event: &Event) {
+        match event {
+            Event::Start(Tag::Link { .. } | Tag::Image { .. }) => self.link_depth += 1,
+            Event::End(TagEnd::Link | TagEnd::Image) => {
+                self.link_depth = self.link_depth.saturating_sub(1)
+            }
+            Event::InlineHtml(html) => match anchor_tag(html) {
+                (true, _) => self.link_depth += 1,
+                (_, true) => self.link_depth = self.link_depth.saturating_sub(1),
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    pub(crate) fn push<'a>(&mut self, text: CowStr<'a>, events: &mut Vec<Event<'a>>) {
+        if self.link_depth > 0 {
+            events.push(Event::Text(text));
+            return;
+        }
+        self.push_segment(&text, Stage::Autolink, events);
+    }
+
+    fn find(&mut self, stage: Stage, text: &str) -> Vec<(Range<usize>, Replacement)> {
+        match stage {
+            Stage::Autolink => autolink::find(text)
+                .into_iter()
+                .map(|link| {
+                    let replacement = Replacement::Link {
+                        href: link.href,
+                        text: text[link.range.clone()].to_string(),
+                    };
+                    (link.range, replacement)
+                })
+                .collect(),
+            Stage::Reference => reference::find(text, self.references)
+                .into_iter()
+                .map(|found| {
+                    if !self.found_references.contains(&found.reference) {
+                        self.found_references.push(found.reference);
+                    }
+                    (found.range, Replacement::Html(found.html))
+                })
+                .collect(),
+            Stage::Emoji if self.emoji.enabled => emoji::find(text)
+                .into_iter()
+                .map(|shortcode| {
+                    let replacement = match &self.emoji.image_url {
+                        Some(template) => {
+                            Replacement::Html(emoji::render_image(&shortcode, template))
+                        }
+                        None => Replacement::Text(shortcode.emoji.to_string()),
+                    };
+                    (shortcode.range, replacement)
+                })
+                .collect(),
+            Stage::Abbreviation => abbreviation::find(text, self.abbreviations)
+                .into_iter()
+                .map(|(range, html)| (range, Replacement::Html(html)))
+                .collect(),
+            Stage::Emoji | Stage::Done => Vec::new(),
+        }
+    }
+
+    fn push_segment(&mut self, text: &str, stage: Stage, events: &mut Vec<Event<'_>>) {
+        if text.is_empty() {
+            return;
+        }
+        if let Stage::Done = stage {
+            events.push(Event::Text(CowStr::from(text.to_string())));
+            return;
+        }
+
+        let mut last_end = 0;
+        for (range, replacement) in self.find(stage, text) {
+            self.push_segment(&text[last_end..range.start], stage.next(), events);
+            match replacement {
+                Replacement::Link { href, text } => {
+                    events.push(Event::Start(Tag::Link {
+                        link_type: LinkType::Autolink,
+                        dest_url: CowStr::from(href),
+                        title: CowStr::Borrowed(""),
+                        id: CowStr::Borrowed(""),
+                    }));
+                    events.push(Event::Text(CowStr::from(text)));
+                    events.push(Event::End(TagEnd::Link));
+                }
+                Replacement::Text(text) => events.push(Event::Text(CowStr::from(text))),
+                Replacement::Html(html) => events.push(Event::InlineHtml(CowStr::from(html))),
+            }
+            last_end = range.end;
+        }
+        self.push_segment(&text[last_end..], stage.next(), events);
+    }
+}
@@ -0,0 +1,151 @@
+// Wiki 链接：`[[Page]]`、`[[Page|标签]]` 与 `![[image.png]]`（Obsidian 风格）
+//
+// 语法由 pulldown-cmark 的 `ENABLE_WIKILINKS` 解析，这里只处理链接目标：
+// - 目标中 `#` 之前的部分是页面名，交给解析器（`WikiLinkResolver`）得到 href 并判断页面是否存在，
+//   `#` 之后的锚点原样附加到 href 末尾；`[[#Section]]` 只链接到当前页面的锚点
+// - 不存在的页面输出 `<a class="wikilink-missing">`，并记录在 `RenderResult::missing_wikilinks` 中
+// - `![[image.png]]` 的图片地址同样由解析器决定；不存在的图片只记录，不改变输出
+// 没有解析器时 href 就是页面名，所有页面都视为存在。
+
+use pulldown_cmark::{CowStr, Event, LinkType, Tag, TagEnd, html};
+use wasm_bindgen::prelude::*;
+
+/// 页面名解析的结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WikiLinkTarget {
+    /// 链接地址（不含 `#锚点`）
+    pub href: String,
+    /// 页面是否存在，不存在时链接带有 `class="wikilink-missing"`
+    pub exists: bool,
+}
+
+/// 把 Wiki 链接的页面名解析为链接地址
+pub trait WikiLinkResolver {
+    fn resolve(&self, page: &str) -> WikiLinkTarget;
+}
+
+impl<F: Fn(&str) -> WikiLinkTarget> WikiLinkResolver for F {
+    fn resolve(&self, page: &str) -> WikiLinkTarget {
+        self(page)
+    }
+}
+
+// JS 侧的解析回调 `(page) => ({ href, exists })`，返回值由 wrapper.js 补全默认值
+#[wasm_bindgen]
+extern "C" {
+    pub type WikiLinkCallback;
+
+    #[wasm_bindgen(method, js_name = call)]
+    fn call(this: &WikiLinkCallback, context: &JsValue, page: &str) -> JsWikiLinkTarget;
+
+    type JsWikiLinkTarget;
+
+    #[wasm_bindgen(method, getter)]
+    fn href(this: &JsWikiLinkTarget) -> String;
+
+    #[wasm_bindgen(method, getter)]
+    fn exists(this: &JsWikiLinkTarget) -> bool;
+}
+
+impl WikiLinkResolver for WikiLinkCallback {
+    fn resolve(&self, page: &str) -> WikiLinkTarget {
+        let target = self.call(&JsValue::NULL, page);
+        WikiLinkTarget {
+            href: target.href(),
+            exists: target.exists(),
+        }
+    }
+}
+
+pub(crate) struct WikiLinks<'r> {
+    resolver: &'r dyn WikiLinkResolver,
+    missing: Vec<String>,
+    // 当前是否在不存在页面的链接中（链接不能嵌套）
+    in_missing_link: bool,
+}
+
+impl<'r> WikiLinks<'r> {
+    pub(crate) fn new(resolver: &'r dyn WikiLinkResolver) -> Self {
+        WikiLinks {
+            resolver,
+            missing: Vec::new(),
+            in_missing_link: false,
+        }
+    }
+
+    /// 不存在的页面，按首次出现的顺序去重
+    pub(crate) fn into_missing(self) -> Vec<String> {
+        self.missing
+    }
+
+    // 解析链接目标，返回完整的 href 与页面是否存在
+    fn resolve(&mut self, target: &str) -> (String, bool) {
+        let (page, fragment) = match target.find('#') {
+            Some(index) => target.split_at(index),
+            None => (target, ""),
+        };
+        let page = page.trim();
+        if page.is_empty() {
+            return (fragment.to_string(), true);
+        }
+
+        let resolved = self.resolver.resolve(page);
+        if !resolved.exists && !self.missing.iter().any(|missing| missing == page) {
+            self.missing.push(page.to_string());
+        }
+        (resolved.href + fragment, resolved.exists)
+    }
+
+    /// 处理 Wiki 链接 / 图片的开始事件，其他事件原样返回
+    pub(crate) fn start<'a>(&mut self, tag: Tag<'a>) -> Event<'a> {
+        match tag {
+            Tag::Link {
+                link_type: link_type @ LinkType::WikiLink { .. },
+                dest_url,
+                title,
+                id,
+            } => {
+                let (href, exists) = self.resolve(&dest_url);
+                let link = Tag::Link {
+                    link_type,
+                    dest_url: CowStr::from(href),
+                    title,
+                    id,
+                };
+                if exists {
+                    return Event::Start(link);
+                }
+
+                // 用 pulldown-cmark 输出开始标签，href 的转义与普通链接相同
+                let mut open = String::new();
+                html::push_html(&mut open, std::iter::once(Event::Start(link)));
+                self.in_missing_link = true;
+                Event::InlineHtml(CowStr::from(format!(
+                    "{} class=\"wikilink-missing\">",
+                    open.trim_end_matches('>')
+                )))
+            }
+            Tag::Image {
+                link_type: link_type @ LinkType::WikiLink { .. },
+                dest_url,
+                title,
+                id,
+            } => Event::Start(Tag::Image {
+                link_type,
+                dest_url: CowStr::from(self.resolve(&dest_url).0),
+                title,
+                id,
+            }),
+            tag => Event::Start(tag),
+        }
+    }
+
+    /// 链接的结束事件：不存在页面的链接以 HTML 结束
+    pub(crate) fn end_link<'a>(&mut self) -> Event<'a> {
+        if std::mem::take(&mut self.in_missing_link) {
+            Event::InlineHtml(CowStr::Borrowed("</a>"))
+        } else {
+            Event::End(TagEnd::Link)
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use awsm_markdown_renderer::{RenderOptions, render_markdown_with_options};
+
+fn abbreviation_options() -> RenderOptions {
+    RenderOptions {
+        abbreviations: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_abbreviations() {
+    let html = render_markdown_with_options(
+        "The HTML specification is maintained by the W3C.\n\n*[HTML]: Hyper Text Markup Language\n*[W3C]:  World Wide Web Consortium\n",
+        &abbreviation_options(),
+    );
+    assert_eq!(
+        html,
+        concat!(
+            "<p>The <abbr title=\"Hyper Text Markup Language\">HTML</abbr> specification ",
+            "is maintained by the <abbr title=\"World Wide Web Consortium\">W3C</abbr>.</p>\n",
+        )
+    );
+}
+
+#[test]
+fn test_abbreviations_match_whole_words_only() {
+    let html = render_markdown_with_options(
+        "*[HTML]: Hyper Text Markup Language\n*[HTML5]: HTML version 5\n*[ABC]:\nXHTML, HTML_x, html, HTML5 and **HTML**s. ABC.",
+        &abbreviation_options(),
+    );
+    assert_eq!(
+        html,
+        concat!(
+            "<p>XHTML, HTML_x, html, <abbr title=\"HTML version 5\">HTML5</abbr> and ",
+            "<strong><abbr title=\"Hyper Text Markup Language\">HTML</abbr></strong>s. ",
+            "<abbr>ABC</abbr>.</p>\n",
+        )
+    );
+}
+
+#[test]
+fn test_abbreviation_titles_are_escaped_and_redefinitions_win() {
+    let html = render_markdown_with_options(
+        "*[R&D]: old\n*[R&D]: Research & \"Development\"\n\nR&D team",
+        &abbreviation_options(),
+    );
+    assert_eq!(
+        html,
+        "<p><abbr title=\"Research &amp; &quot;Development&quot;\">R&amp;D</abbr> team</p>\n"
+    );
+}
+
+#[test]
+fn test_abbreviation_definitions_in_headings_and_lists() {
+    let html = render_markdown_with_options(
+        "# CSS guide\n\n- uses CSS\n*[CSS]: Cascading Style Sheets",
+        &abbreviation_options(),
+    );
+    assert_eq!(
+        html,
+        concat!(
+            "<h1><abbr title=\"Cascading Style Sheets\">CSS</abbr> guide</h1>\n",
+            "<ul>\n<li>uses <abbr title=\"Cascading Style Sheets\">CSS</abbr></li>\n</ul>\n",
+        )
+    );
+}
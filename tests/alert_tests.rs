@@ -0,0 +1,75 @@
+use awsm_markdown_renderer::render_markdown;
+
+#[test]
+fn test_note_alert_markup() {
+    let html = render_markdown("> [!NOTE]\n> Useful information.");
+    assert!(
+        html.starts_with("<div class=\"markdown-alert markdown-alert-note\">\n<p class=\"markdown-alert-title\"><svg class=\"octicon octicon-info mr-2\" viewBox=\"0 0 16 16\" version=\"1.1\" width=\"16\" height=\"16\" aria-hidden=\"true\"><path d=\""),
+        "got: {}",
+        html
+    );
+    assert!(html.contains("</svg>Note</p>\n<p>Useful information.</p>\n</div>"));
+    assert!(!html.contains("<blockquote"));
+    assert!(!html.contains("[!NOTE]"));
+}
+
+#[test]
+fn test_all_alert_kinds() {
+    let kinds = [
+        ("NOTE", "note", "info", "Note"),
+        ("TIP", "tip", "light-bulb", "Tip"),
+        ("IMPORTANT", "important", "report", "Important"),
+        ("WARNING", "warning", "alert", "Warning"),
+        ("CAUTION", "caution", "stop", "Caution"),
+    ];
+    for (marker, class, icon, title) in kinds {
+        let html = render_markdown(&format!("> [!{}]\n> Body", marker));
+        assert!(
+            html.contains(&format!(
+                r#"<div class="markdown-alert markdown-alert-{}">"#,
+                class
+            )),
+            "[!{}] got: {}",
+            marker,
+            html
+        );
+        assert!(html.contains(&format!("octicon-{} mr-2", icon)));
+        assert!(html.contains(&format!("</svg>{}</p>", title)));
+    }
+}
+
+#[test]
+fn test_alert_marker_is_case_insensitive() {
+    let html = render_markdown("> [!warning]\n> Careful");
+    assert!(html.contains("markdown-alert-warning"), "got: {}", html);
+}
+
+#[test]
+fn test_alert_body_keeps_markdown_math_and_code() {
+    let html =
+        render_markdown("> [!TIP]\n> Use **bold** and $x^2$.\n>\n> ```rust\n> fn main() {}\n> ```");
+    assert!(html.contains("<strong>bold</strong>"), "got: {}", html);
+    assert!(html.contains("<msup><mi>x</mi><mn>2</mn></msup>"));
+    assert!(html.contains(r#"<pre><code class="language-rust">"#));
+    assert!(html.trim_end().ends_with("</div>"));
+}
+
+#[test]
+fn test_plain_blockquotes_are_unchanged() {
+    let html = render_markdown("> Just a quote");
+    assert_eq!(html, "<blockquote>\n<p>Just a quote</p>\n</blockquote>\n");
+
+    // 标记后面有文字时不是提示块（与 GitHub 相同）
+    let html = render_markdown("> [!NOTE] not an alert");
+    assert!(html.contains("<blockquote>"), "got: {}", html);
+
+    let html = render_markdown("> [!UNKNOWN]\n> text");
+    assert!(html.contains("<blockquote>"), "got: {}", html);
+}
+
+#[test]
+fn test_nested_quote_inside_alert() {
+    let html = render_markdown("> [!NOTE]\n> > quoted");
+    assert!(html.contains("markdown-alert-note"), "got: {}", html);
+    assert!(html.contains("<blockquote>\n<p>quoted</p>\n</blockquote>\n</div>"));
+}
@@ -0,0 +1,158 @@
+use awsm_markdown_renderer::{RenderOptions, render_markdown, render_markdown_with_options};
+
+mod common;
+
+// GFM 规范 “Autolinks (extension)” 一节的示例：(输入, 期望输出)
+const GFM_SPEC_EXAMPLES: &[(&str, &str)] = &[
+    (
+        "www.commonmark.org",
+        "<p><a href=\"http://www.commonmark.org\">www.commonmark.org</a></p>\n",
+    ),
+    (
+        "Visit www.commonmark.org/help for more information.",
+        "<p>Visit <a href=\"http://www.commonmark.org/help\">www.commonmark.org/help</a> for more information.</p>\n",
+    ),
+    (
+        "Visit www.commonmark.org.\n\nVisit www.commonmark.org/a.b.",
+        concat!(
+            "<p>Visit <a href=\"http://www.commonmark.org\">www.commonmark.org</a>.</p>\n",
+            "<p>Visit <a href=\"http://www.commonmark.org/a.b\">www.commonmark.org/a.b</a>.</p>\n",
+        ),
+    ),
+    (
+        "www.google.com/search?q=Markup+(business)\n\nwww.google.com/search?q=Markup+(business)))\n\n(www.google.com/search?q=Markup+(business))\n\n(www.google.com/search?q=Markup+(business)",
+        concat!(
+            "<p><a href=\"http://www.google.com/search?q=Markup+(business)\">www.google.com/search?q=Markup+(business)</a></p>\n",
+            "<p><a href=\"http://www.google.com/search?q=Markup+(business)\">www.google.com/search?q=Markup+(business)</a>))</p>\n",
+            "<p>(<a href=\"http://www.google.com/search?q=Markup+(business)\">www.google.com/search?q=Markup+(business)</a>)</p>\n",
+            "<p>(<a href=\"http://www.google.com/search?q=Markup+(business)\">www.google.com/search?q=Markup+(business)</a></p>\n",
+        ),
+    ),
+    (
+        "www.google.com/search?q=(business))+ok",
+        "<p><a href=\"http://www.google.com/search?q=(business))+ok\">www.google.com/search?q=(business))+ok</a></p>\n",
+    ),
+    (
+        "www.google.com/search?q=commonmark&hl=en\n\nwww.google.com/search?q=commonmark&hl;",
+        concat!(
+            "<p><a href=\"http://www.google.com/search?q=commonmark&amp;hl=en\">www.google.com/search?q=commonmark&amp;hl=en</a></p>\n",
+            "<p><a href=\"http://www.google.com/search?q=commonmark\">www.google.com/search?q=commonmark</a>&amp;hl;</p>\n",
+        ),
+    ),
+    (
+        "www.commonmark.org/he<lp",
+        "<p><a href=\"http://www.commonmark.org/he\">www.commonmark.org/he</a>&lt;lp</p>\n",
+    ),
+    (
+        "http://commonmark.org\n\n(Visit https://encrypted.google.com/search?q=Markup+(business))\n\nAnonymous FTP is available at ftp://foo.bar.baz.",
+        concat!(
+            "<p><a href=\"http://commonmark.org\">http://commonmark.org</a></p>\n",
+            "<p>(Visit <a href=\"https://encrypted.google.com/search?q=Markup+(business)\">https://encrypted.google.com/search?q=Markup+(business)</a>)</p>\n",
+            "<p>Anonymous FTP is available at <a href=\"ftp://foo.bar.baz\">ftp://foo.bar.baz</a>.</p>\n",
+        ),
+    ),
+    (
+        "foo@bar.baz",
+        "<p><a href=\"mailto:foo@bar.baz\">foo@bar.baz</a></p>\n",
+    ),
+    (
+        "hello@mail+xyz.example isn't valid, but hello+xyz@mail.example is.",
+        "<p>hello@mail+xyz.example isn't valid, but <a href=\"mailto:hello+xyz@mail.example\">hello+xyz@mail.example</a> is.</p>\n",
+    ),
+    (
+        "a.b-c_d@a.b\n\na.b-c_d@a.b.\n\na.b-c_d@a.b-\n\na.b-c_d@a.b_",
+        concat!(
+            "<p><a href=\"mailto:a.b-c_d@a.b\">a.b-c_d@a.b</a></p>\n",
+            "<p><a href=\"mailto:a.b-c_d@a.b\">a.b-c_d@a.b</a>.</p>\n",
+            "<p>a.b-c_d@a.b-</p>\n",
+            "<p>a.b-c_d@a.b_</p>\n",
+        ),
+    ),
+    (
+        "mailto:foo@bar.baz\n\nmailto:a.b-c_d@a.b\n\nmailto:a.b-c_d@a.b.\n\nmailto:a.b-c_d@a.b/\n\nmailto:a.b-c_d@a.b-\n\nmailto:a.b-c_d@a.b_\n\nxmpp:foo@bar.baz\n\nxmpp:foo@bar.baz.",
+        concat!(
+            "<p><a href=\"mailto:foo@bar.baz\">mailto:foo@bar.baz</a></p>\n",
+            "<p><a href=\"mailto:a.b-c_d@a.b\">mailto:a.b-c_d@a.b</a></p>\n",
+            "<p><a href=\"mailto:a.b-c_d@a.b\">mailto:a.b-c_d@a.b</a>.</p>\n",
+            "<p><a href=\"mailto:a.b-c_d@a.b\">mailto:a.b-c_d@a.b</a>/</p>\n",
+            "<p>mailto:a.b-c_d@a.b-</p>\n",
+            "<p>mailto:a.b-c_d@a.b_</p>\n",
+            "<p><a href=\"xmpp:foo@bar.baz\">xmpp:foo@bar.baz</a></p>\n",
+            "<p><a href=\"xmpp:foo@bar.baz\">xmpp:foo@bar.baz</a>.</p>\n",
+        ),
+    ),
+    (
+        "xmpp:foo@bar.baz/txt\n\nxmpp:foo@bar.baz/txt@bin\n\nxmpp:foo@bar.baz/txt@bin.com",
+        concat!(
+            "<p><a href=\"xmpp:foo@bar.baz/txt\">xmpp:foo@bar.baz/txt</a></p>\n",
+            "<p><a href=\"xmpp:foo@bar.baz/txt@bin\">xmpp:foo@bar.baz/txt@bin</a></p>\n",
+            "<p><a href=\"xmpp:foo@bar.baz/txt@bin.com\">xmpp:foo@bar.baz/txt@bin.com</a></p>\n",
+        ),
+    ),
+    (
+        "xmpp:foo@bar.baz/txt/bin",
+        "<p><a href=\"xmpp:foo@bar.baz/txt\">xmpp:foo@bar.baz/txt</a>/bin</p>\n",
+    ),
+];
+
+#[test]
+fn test_gfm_spec_autolink_examples() {
+    for parser in common::PARSERS {
+        let mut options = RenderOptions::default();
+        options.math.parser = parser;
+        for (input, expected) in GFM_SPEC_EXAMPLES {
+            assert_eq!(
+                render_markdown_with_options(input, &options),
+                *expected,
+                "{:?}: {}",
+                parser,
+                input
+            );
+        }
+    }
+}
+
+#[test]
+fn test_autolinks_need_a_boundary() {
+    let html = render_markdown("foo.www.example.com xhttp://example.com *www.example.com*");
+    assert_eq!(
+        html,
+        "<p>foo.www.example.com xhttp://example.com <em><a href=\"http://www.example.com\">www.example.com</a></em></p>\n"
+    );
+}
+
+#[test]
+fn test_autolink_domain_rules() {
+    // 最后两段不能包含 `_`
+    let html = render_markdown("www.ex_ample.com www.example_.com www.sub_domain.example.com");
+    assert_eq!(
+        html,
+        "<p>www.ex_ample.com www.example_.com <a href=\"http://www.sub_domain.example.com\">www.sub_domain.example.com</a></p>\n"
+    );
+
+    let html = render_markdown("Run http://localhost:3000/x.");
+    assert_eq!(
+        html,
+        "<p>Run <a href=\"http://localhost:3000/x\">http://localhost:3000/x</a>.</p>\n"
+    );
+}
+
+#[test]
+fn test_autolinks_next_to_math_and_smart_punctuation() {
+    let html = render_markdown("See www.example.com/$x$ and $y$ at https://example.org.");
+    assert!(
+        html.contains("<a href=\"https://example.org\">https://example.org</a>.</p>"),
+        "got: {}",
+        html
+    );
+
+    let options = RenderOptions {
+        smart_punctuation: true,
+        ..Default::default()
+    };
+    let html = render_markdown_with_options("\"www.example.com\" and (foo@bar.baz)", &options);
+    assert_eq!(
+        html,
+        "<p>“www.example.com” and (<a href=\"mailto:foo@bar.baz\">foo@bar.baz</a>)</p>\n"
+    );
+}
@@ -0,0 +1,408 @@
+use awsm_markdown_renderer::{RenderOptions, render_markdown, render_markdown_with_options};
+
+#[test]
+fn test_ansi_basic_colors() {
+    let input = "```ansi\n\x1b[31merror\x1b[0m: build failed\n```";
+    let html = render_markdown(input);
+
+    assert!(html.contains("class=\"language-ansi\""));
+    assert!(
+        html.contains("<span class=\"ansi-red\">error</span>"),
+        "SGR 31 should map to ansi-red, got: {}",
+        html
+    );
+    assert!(html.contains(": build failed"));
+    // 原始转义序列不应出现在输出中
+    assert!(!html.contains('\x1b'), "Raw ESC should be stripped");
+    assert!(!html.contains("[31m"), "Raw SGR params should be stripped");
+}
+
+#[test]
+fn test_ansi_bold_underline_and_bright() {
+    let input = "```ansi\n\x1b[1;4;92mPASS\x1b[22;24m ok\x1b[0m\n```";
+    let html = render_markdown(input);
+
+    assert!(
+        html.contains("<span class=\"ansi-bright-green ansi-bold ansi-underline\">PASS</span>"),
+        "got: {}",
+        html
+    );
+    assert!(html.contains("<span class=\"ansi-bright-green\"> ok</span>"));
+}
+
+#[test]
+fn test_ansi_256_and_truecolor() {
+    let input = "```ansi\n\x1b[38;5;208morange\x1b[0m \x1b[48;2;10;20;30mbg\x1b[0m \x1b[38;5;1mred\x1b[0m\n```";
+    let html = render_markdown(input);
+
+    // 256 色中的 208 → #ff8700
+    assert!(
+        html.contains("style=\"color:#ff8700\">orange</span>"),
+        "got: {}",
+        html
+    );
+    assert!(html.contains("style=\"background-color:#0a141e\">bg</span>"));
+    // 256 色中的 0-15 仍然使用类名
+    assert!(html.contains("<span class=\"ansi-red\">red</span>"));
+}
+
+#[test]
+fn test_ansi_textual_escape_and_html_escaping() {
+    let input = "```console\n\\x1b[33mwarning\\x1b[0m: <unused> & \"x\"\n```";
+    let html = render_markdown(input);
+
+    assert!(
+        html.contains("<span class=\"ansi-yellow\">warning</span>"),
+        "got: {}",
+        html
+    );
+    assert!(html.contains("&lt;unused&gt; &amp; &quot;x&quot;"));
+    assert!(!html.contains("\\x1b"));
+}
+
+#[test]
+fn test_ansi_non_sgr_sequences_dropped() {
+    let input = "```ansi\n\x1b[2K\x1b[1Gprogress 100%\n```";
+    let html = render_markdown(input);
+
+    assert!(html.contains("progress 100%"));
+    assert!(
+        !html.contains("<span"),
+        "Non-SGR sequences produce no spans: {}",
+        html
+    );
+}
+
+#[test]
+fn test_shell_session_prompt_and_output() {
+    let input = "```console\n$ echo if then\nif then\n# whoami\nroot\n```";
+    let html = render_markdown(input);
+
+    assert!(html.contains("class=\"language-console\""));
+    assert!(
+        html.contains("<span class=\"shell-prompt\" aria-hidden=\"true\">$ </span>"),
+        "got: {}",
+        html
+    );
+    assert!(html.contains("<span class=\"shell-prompt\" aria-hidden=\"true\"># </span>"));
+    // 命令部分使用 bash 高亮
+    assert!(
+        html.contains("<span class=\"shell-command\"><span class=\"source shell bash\">"),
+        "got: {}",
+        html
+    );
+    // 输出行不做语法高亮
+    assert!(html.contains("<span class=\"shell-output\">if then</span>"));
+    assert!(html.contains("<span class=\"shell-output\">root</span>"));
+}
+
+#[test]
+fn test_shell_session_line_continuation() {
+    let input = "```shell-session\n$ cargo build \\\n    --release\n   Compiling app\n```";
+    let html = render_markdown(input);
+
+    let prompts: Vec<_> = html.match_indices("shell-prompt").collect();
+    assert_eq!(prompts.len(), 1, "Continuation is part of the same command");
+    assert!(
+        !html.contains("<span class=\"shell-output\">    --release"),
+        "Continuation line should not be treated as output: {}",
+        html
+    );
+    assert!(html.contains("<span class=\"shell-output\">   Compiling app</span>"));
+}
+
+#[test]
+fn test_shell_session_output_escaped() {
+    let input = "```console\n$ cat index.html\n<p>&amp;</p>\n```";
+    let html = render_markdown(input);
+
+    assert!(html.contains("<span class=\"shell-output\">&lt;p&gt;&amp;amp;&lt;/p&gt;</span>"));
+}
+
+fn container_options() -> RenderOptions {
+    RenderOptions {
+        code_block_container: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_info_string_attributes_do_not_leak_into_language() {
+    let input = "```rust title=\"main.rs\"\nfn main() {}\n```";
+    let html = render_markdown(input);
+
+    assert!(html.contains("class=\"language-rust\""), "got: {}", html);
+    assert!(html.contains("<span class=\"source rust\">"));
+    // 默认不输出容器
+    assert!(!html.contains("awsm-code-block"));
+}
+
+#[test]
+fn test_code_block_container() {
+    let input = "```rust title=\"src/main.rs\"\nfn main() {}\n```";
+    let html = render_markdown_with_options(input, &container_options());
+
+    assert!(
+        html.starts_with("<div class=\"awsm-code-block\" data-lang=\"rust\""),
+        "got: {}",
+        html
+    );
+    assert!(html.contains("<span class=\"code-block-lang\">rust</span>"));
+    assert!(html.contains("<span class=\"code-block-title\">src/main.rs</span>"));
+    assert!(html.contains("data-code=\"fn main() {}\n\""));
+    assert!(html.contains("<pre><code class=\"language-rust\">"));
+}
+
+#[test]
+fn test_code_block_container_strips_ansi_from_copy_text() {
+    let input = "```ansi\n\x1b[31mred\x1b[0m \\x1b[1mbold\\x1b[0m\n```\n\n```console\n$ ls\n\x1b[34mdir\x1b[0m\n```";
+    let html = render_markdown_with_options(input, &container_options());
+
+    assert!(html.contains("data-code=\"red bold\n\""), "got: {}", html);
+    assert!(html.contains("data-code=\"$ ls\ndir\n\""), "got: {}", html);
+    assert!(!html.contains('\x1b'));
+}
+
+#[test]
+fn test_code_block_container_escapes_source() {
+    let input = "```html\n<a href=\"x\">'&'</a>\n```";
+    let html = render_markdown_with_options(input, &container_options());
+
+    assert!(
+        html.contains("data-code=\"&lt;a href=&quot;x&quot;&gt;&#39;&amp;&#39;&lt;/a&gt;\n\""),
+        "got: {}",
+        html
+    );
+    // 没有 title 时不输出标题 span
+    assert!(!html.contains("code-block-title"));
+}
+
+#[test]
+fn test_render_options_from_json() {
+    let options = RenderOptions::from_json(r#"{"codeBlockContainer": true}"#).unwrap();
+    assert!(options.code_block_container);
+
+    let options = RenderOptions::from_json("").unwrap();
+    assert!(!options.code_block_container);
+
+    assert!(RenderOptions::from_json("{not json").is_err());
+}
+
+#[test]
+fn test_code_group_from_tab_attributes() {
+    let input = r#"
+```bash tab="npm"
+npm install awsm
+```
+
+```bash tab="pnpm"
+pnpm add awsm
+```
+
+Next paragraph.
+"#;
+    let html = render_markdown(input);
+
+    let groups: Vec<_> = html.match_indices("<div class=\"code-group\">").collect();
+    assert_eq!(
+        groups.len(),
+        1,
+        "Adjacent tab blocks form one group: {}",
+        html
+    );
+    assert!(html.contains("role=\"tablist\""));
+    assert!(html.contains(
+        "<label for=\"code-group-1-input-0\" id=\"code-group-1-tab-0\" role=\"tab\" aria-selected=\"true\" aria-controls=\"code-group-1-panel-0\">npm</label>"
+    ));
+    assert!(html.contains("aria-controls=\"code-group-1-panel-1\">pnpm</label>"));
+    assert!(html.contains("id=\"code-group-1-panel-1\" role=\"tabpanel\""));
+    // 无 JS 回退：radio 输入，第一个默认选中
+    assert!(html.contains(
+        "<input type=\"radio\" class=\"code-group-input\" name=\"code-group-1\" id=\"code-group-1-input-0\" checked>"
+    ));
+    // 分组之后的内容顺序保持不变
+    let group_end = html.find("</div></div></div>").unwrap();
+    assert!(html.find("<p>Next paragraph.</p>").unwrap() > group_end);
+}
+
+#[test]
+fn test_code_group_container() {
+    let input = r#"
+:::code-group
+```js
+const a = 1;
+```
+```ts title="config.ts"
+const b: number = 2;
+```
+:::
+
+After
+"#;
+    let html = render_markdown(input);
+
+    assert!(html.contains("<div class=\"code-group\">"), "got: {}", html);
+    // 标签名：无 tab 属性时依次取 title、语言
+    assert!(html.contains("aria-controls=\"code-group-1-panel-0\">js</label>"));
+    assert!(html.contains("aria-controls=\"code-group-1-panel-1\">config.ts</label>"));
+    // 容器标记本身不输出
+    assert!(!html.contains(":::"));
+    assert!(!html.contains("<p></p>"));
+    assert!(html.contains("<p>After</p>"));
+}
+
+#[test]
+fn test_content_between_grouped_blocks_keeps_source_order() {
+    // 两个 tab 代码块之间的公式块
+    let input = "```sh tab=\"a\"\nfirst\n```\n```math\nx^2\n```\n```sh tab=\"b\"\nsecond\n```\n";
+    let html = render_markdown(input);
+    let first = html.find("first").unwrap();
+    let math = html.find("<math").unwrap();
+    let second = html.find("second").unwrap();
+    assert!(first < math && math < second, "got: {}", html);
+    assert!(html.contains("name=\"code-group-2\""));
+
+    // ::: code-group 容器中夹在代码块之间的段落
+    let input = "::: code-group\n```js\nfirst\n```\n\nBetween\n\n```ts\nsecond\n```\n:::\n";
+    let html = render_markdown(input);
+    let first = html.find("first").unwrap();
+    let between = html.find("<p>Between</p>").unwrap();
+    let second = html.find("second").unwrap();
+    assert!(first < between && between < second, "got: {}", html);
+    assert!(!html.contains(":::"));
+}
+
+#[test]
+fn test_separate_code_groups_get_unique_ids() {
+    let input = "```sh tab=\"a\"\nx\n```\n\ntext\n\n```sh tab=\"b\"\ny\n```\n\n```sh\nplain\n```\n";
+    let html = render_markdown(input);
+
+    assert!(html.contains("name=\"code-group-1\""));
+    assert!(html.contains("name=\"code-group-2\""), "got: {}", html);
+    // 没有 tab 属性的相邻代码块不加入分组，并且保持在分组之后
+    let plain = html.find("plain").unwrap();
+    assert!(plain > html.find("name=\"code-group-2\"").unwrap());
+    assert!(!html[plain..].contains("code-group-panel"));
+}
+
+fn numbered_lines(count: usize) -> String {
+    (1..=count)
+        .map(|i| format!("let line_{} = \"multi\nline\";\n", i))
+        .collect()
+}
+
+// 每个 <details> 区域内的标签必须平衡
+fn assert_balanced_spans(html: &str) {
+    for part in html.split("<details").skip(1) {
+        let region = &part[..part.find("</details>").expect("details closed")];
+        assert_eq!(
+            region.matches("<span").count(),
+            region.matches("</span>").count(),
+            "Unbalanced spans in fold region: {}",
+            region
+        );
+    }
+}
+
+#[test]
+fn test_collapse_wraps_block_in_details() {
+    let input = "```rust collapse\nfn main() {}\nfn other() {}\n```";
+    let html = render_markdown(input);
+
+    assert!(
+        html.starts_with(
+            "<details class=\"code-collapse\"><summary>rust (2 lines)</summary><pre><code class=\"language-rust\">"
+        ),
+        "got: {}",
+        html
+    );
+    assert!(html.ends_with("</code></pre></details>"));
+}
+
+#[test]
+fn test_max_lines_folds_remaining_lines() {
+    let input = format!(
+        "```rust maxLines=2\n{}```",
+        "fn a() {}\nfn b() {}\nfn c() {}\nfn d() {}\n"
+    );
+    let html = render_markdown(&input);
+
+    assert!(html.contains("<details class=\"code-fold\"><summary>Show 2 more lines</summary>"));
+    // 前两行在折叠区域之前
+    let fold_start = html.find("<details").unwrap();
+    assert!(html[..fold_start].contains(">b</span>"), "got: {}", html);
+    assert!(html[fold_start..].contains(">c</span>"));
+    assert!(html[fold_start..].contains(">d</span>"));
+    assert_balanced_spans(&html);
+}
+
+#[test]
+fn test_fold_ranges_keep_spans_balanced() {
+    // 字符串字面量跨越多行，高亮器的 <span> 会跨越折叠边界
+    let input = format!("```rust fold=2-3,6-7\n{}```", numbered_lines(4));
+    let html = render_markdown(&input);
+
+    assert!(
+        html.contains("<summary>Lines 2–3</summary>"),
+        "got: {}",
+        html
+    );
+    assert!(html.contains("<summary>Lines 6–7</summary>"));
+    assert_eq!(html.matches("<details").count(), 2);
+    assert_eq!(
+        html.matches("<span").count(),
+        html.matches("</span>").count()
+    );
+    assert_balanced_spans(&html);
+}
+
+#[test]
+fn test_folds_close_and_reopen_pre() {
+    let input = format!("```rust fold=1-2,4-5\n{}```", numbered_lines(3));
+    let html = render_markdown(&input);
+
+    // <details> 不能出现在 <pre> 内
+    for (start, _) in html.match_indices("<details") {
+        assert_eq!(
+            html[..start].matches("<pre>").count(),
+            html[..start].matches("</pre>").count(),
+            "got: {}",
+            html
+        );
+    }
+    assert!(html.starts_with(
+        "<details class=\"code-fold\"><summary>Lines 1–2</summary><pre><code class=\"language-rust\">"
+    ));
+    assert!(html.contains("</code></pre></details><pre><code class=\"language-rust\">"));
+    assert_eq!(html.matches("<pre>").count(), 4);
+    assert_eq!(
+        html.matches("<pre>").count(),
+        html.matches("</pre>").count()
+    );
+}
+
+#[test]
+fn test_fold_ranges_are_clipped_to_max_lines() {
+    let input = format!("```rust maxLines=3 fold=2-5,4-6\n{}```", numbered_lines(4));
+    let html = render_markdown(&input);
+
+    // 与 maxLines 重叠的区间截止到第 3 行，完全在其后的区间忽略
+    assert!(
+        html.contains("<summary>Lines 2–3</summary>"),
+        "got: {}",
+        html
+    );
+    assert!(html.contains("<summary>Show 5 more lines</summary>"));
+    assert!(!html.contains("Lines 4–6"));
+    assert_eq!(html.matches("<details").count(), 2);
+    assert_balanced_spans(&html);
+}
+
+#[test]
+fn test_fold_ignores_invalid_ranges() {
+    let input = "```text fold=5-2,abc\none\ntwo\n```";
+    let html = render_markdown(input);
+
+    assert!(!html.contains("<details"));
+    assert!(html.contains("one\ntwo"));
+}
@@ -0,0 +1,12 @@
+// 各测试文件共用的辅助函数（每个测试文件只用到其中一部分）
+#![allow(dead_code)]
+
+use awsm_markdown_renderer::MathParser;
+
+/// 需要同时覆盖的两种公式解析方式
+pub const PARSERS: [MathParser; 2] = [MathParser::Regex, MathParser::Native];
+
+/// 输出中的 `<math>` 元素数量
+pub fn count_math(html: &str) -> usize {
+    html.matches("<math").count()
+}
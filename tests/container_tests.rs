@@ -0,0 +1,223 @@
+use awsm_markdown_renderer::{
+    Container, RenderOptions, render_markdown, render_markdown_with_options,
+};
+
+#[test]
+fn test_builtin_containers_with_default_titles() {
+    let cases = [
+        ("tip", "div", r#"<p class="custom-container-title">TIP</p>"#),
+        (
+            "warning",
+            "div",
+            r#"<p class="custom-container-title">WARNING</p>"#,
+        ),
+        (
+            "danger",
+            "div",
+            r#"<p class="custom-container-title">DANGER</p>"#,
+        ),
+        ("details", "details", "<summary>Details</summary>"),
+    ];
+    for (name, element, title) in cases {
+        let html = render_markdown(&format!("::: {}\nBody\n:::", name));
+        assert_eq!(
+            html,
+            format!(
+                "<{} class=\"custom-container {}\">\n{}\n<p>Body</p>\n</{}>\n",
+                element, name, title, element
+            ),
+            "::: {}",
+            name
+        );
+    }
+}
+
+#[test]
+fn test_container_custom_title_is_escaped() {
+    let html = render_markdown("::: warning Be <careful>\ntext\n:::");
+    assert!(
+        html.contains(r#"<p class="custom-container-title">Be &lt;careful&gt;</p>"#),
+        "got: {}",
+        html
+    );
+
+    let html = render_markdown("::: details Click to expand\nhidden\n:::");
+    assert!(
+        html.starts_with("<details class=\"custom-container details\">\n<summary>Click to expand</summary>\n<p>hidden</p>"),
+        "got: {}",
+        html
+    );
+}
+
+#[test]
+fn test_container_body_keeps_markdown_math_and_code() {
+    let html =
+        render_markdown("::: tip\nUse **bold** and $x^2$.\n\n```rust\nfn main() {}\n```\n:::");
+    assert!(html.contains("<strong>bold</strong>"), "got: {}", html);
+    assert!(html.contains("<msup><mi>x</mi><mn>2</mn></msup>"));
+    assert!(html.contains(r#"<pre><code class="language-rust"><span class="source rust">"#));
+    assert!(html.trim_end().ends_with("</div>"));
+}
+
+#[test]
+fn test_nested_containers() {
+    let html = render_markdown(":::: danger\n::: tip Inner\nnested\n:::\nouter\n::::");
+    assert_eq!(
+        html,
+        concat!(
+            "<div class=\"custom-container danger\">\n",
+            "<p class=\"custom-container-title\">DANGER</p>\n",
+            "<div class=\"custom-container tip\">\n",
+            "<p class=\"custom-container-title\">Inner</p>\n",
+            "<p>nested</p>\n",
+            "</div>\n",
+            "<p>outer</p>\n",
+            "</div>\n",
+        )
+    );
+}
+
+#[test]
+fn test_registered_containers() {
+    let mut options = RenderOptions::default();
+    options
+        .register_container("note", Container::new("aside", "callout note"))
+        .register_container(
+            "tip",
+            Container {
+                title: Some("Hint".to_string()),
+                ..Container::new("section", "hint")
+            },
+        );
+
+    let html = render_markdown_with_options("::: note Heads up\nbody\n:::", &options);
+    assert_eq!(
+        html,
+        "<aside class=\"callout note\">\n<p class=\"custom-container-title\">Heads up</p>\n<p>body</p>\n</aside>\n"
+    );
+
+    // 注册的容器覆盖内置容器；没有标题时使用默认标题
+    let html = render_markdown_with_options("::: tip\nbody\n:::", &options);
+    assert!(
+        html.starts_with("<section class=\"hint\">\n<p class=\"custom-container-title\">Hint</p>"),
+        "got: {}",
+        html
+    );
+
+    // 没有默认标题也没有写标题时不输出标题
+    let html = render_markdown_with_options("::: note\nbody\n:::", &options);
+    assert_eq!(
+        html,
+        "<aside class=\"callout note\">\n<p>body</p>\n</aside>\n"
+    );
+}
+
+#[test]
+fn test_containers_from_json_options() {
+    let options = RenderOptions::from_json(
+        r#"{"containers": {"info": {"element": "aside", "class": "info-box", "title": "Info"}}}"#,
+    )
+    .unwrap();
+    let html = render_markdown_with_options("::: info\nbody\n:::", &options);
+    assert!(
+        html.starts_with(
+            "<aside class=\"info-box\">\n<p class=\"custom-container-title\">Info</p>"
+        ),
+        "got: {}",
+        html
+    );
+
+    // 非法的元素名回退为 div
+    let options =
+        RenderOptions::from_json(r#"{"containers": {"bad": {"element": "div onclick=x"}}}"#)
+            .unwrap();
+    let html = render_markdown_with_options("::: bad\nbody\n:::", &options);
+    assert!(
+        html.starts_with("<div class=\"custom-container bad\">"),
+        "got: {}",
+        html
+    );
+}
+
+#[test]
+fn test_unknown_containers_and_code_are_left_alone() {
+    let html = render_markdown("::: unknown\nbody\n:::");
+    assert_eq!(html, "<p>::: unknown\nbody\n:::</p>\n");
+
+    // 未注册容器的结束标记不会关闭外层容器
+    let html = render_markdown("::: tip\n::: unknown\nbody\n:::\nafter\n:::");
+    assert!(
+        html.contains("<p>::: unknown\nbody\n:::\nafter</p>\n</div>"),
+        "got: {}",
+        html
+    );
+
+    let html = render_markdown("```md\n::: tip\nx\n:::\n```");
+    assert!(!html.contains("custom-container"), "got: {}", html);
+    assert!(html.contains("::: tip"));
+}
+
+#[test]
+fn test_unclosed_container_closes_at_end_of_document() {
+    let html = render_markdown("::: tip\nbody");
+    assert_eq!(
+        html,
+        "<div class=\"custom-container tip\">\n<p class=\"custom-container-title\">TIP</p>\n<p>body</p>\n</div>\n"
+    );
+}
+
+#[test]
+fn test_code_group_container_still_works() {
+    let html = render_markdown(
+        ":::code-group\n```bash tab=\"npm\"\nnpm i\n```\n```bash tab=\"yarn\"\nyarn\n```\n:::",
+    );
+    assert!(
+        html.starts_with(r#"<div class="code-group">"#),
+        "got: {}",
+        html
+    );
+    assert!(!html.contains(":::"));
+}
+
+#[test]
+fn test_code_group_container_with_space_after_colons() {
+    let html = render_markdown(
+        "::: code-group\n```bash\nnpm i\n```\n```bash\nyarn\n```\n:::\n\n::: tip\nafter\n:::",
+    );
+    assert!(
+        html.starts_with(r#"<div class="code-group">"#),
+        "got: {}",
+        html
+    );
+    assert_eq!(
+        html.matches("code-group-panel\"").count(),
+        2,
+        "got: {}",
+        html
+    );
+    assert!(html.contains("<div class=\"custom-container tip\">"));
+    assert!(!html.contains(":::"));
+}
+
+#[test]
+fn test_containers_inside_list_items_and_blockquotes() {
+    let html = render_markdown("- item\n  ::: tip\n  inside\n  :::\n- next");
+    assert_eq!(
+        html,
+        concat!(
+            "<ul>\n<li>\n<p>item</p>\n<div class=\"custom-container tip\">\n",
+            "<p class=\"custom-container-title\">TIP</p>\n<p>inside</p>\n</div>\n</li>\n",
+            "<li>\n<p>next</p>\n</li>\n</ul>\n",
+        )
+    );
+
+    let html = render_markdown("> ::: warning Careful\n> inside\n> :::\n\nafter");
+    assert_eq!(
+        html,
+        concat!(
+            "<blockquote>\n<div class=\"custom-container warning\">\n",
+            "<p class=\"custom-container-title\">Careful</p>\n<p>inside</p>\n</div>\n",
+            "</blockquote>\n<p>after</p>\n",
+        )
+    );
+}
@@ -0,0 +1,70 @@
+use awsm_markdown_renderer::{EmojiOptions, RenderOptions, render_markdown_with_options};
+
+fn emoji_options() -> RenderOptions {
+    RenderOptions {
+        emoji: EmojiOptions {
+            enabled: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_emoji_shortcodes_to_unicode() {
+    let html = render_markdown_with_options(
+        "Shipped :tada: :+1: :-1: :heart: :rocket:",
+        &emoji_options(),
+    );
+    assert_eq!(html, "<p>Shipped 🎉 👍 👎 ❤\u{FE0F} 🚀</p>\n");
+
+    // 别名、键帽与 ZWJ 序列
+    let html = render_markdown_with_options(
+        ":thumbsup: :satisfied: :one: :rainbow_flag: :us:",
+        &emoji_options(),
+    );
+    assert_eq!(
+        html,
+        "<p>👍 😆 1\u{FE0F}\u{20E3} 🏳\u{FE0F}\u{200D}🌈 🇺🇸</p>\n"
+    );
+}
+
+#[test]
+fn test_unknown_shortcodes_are_left_alone() {
+    let html = render_markdown_with_options(
+        "at 10:30:00, :not_an_emoji: and :Smile: but 10:30:tada:",
+        &emoji_options(),
+    );
+    assert_eq!(
+        html,
+        "<p>at 10:30:00, :not_an_emoji: and :Smile: but 10:30🎉</p>\n"
+    );
+}
+
+#[test]
+fn test_emoji_image_template() {
+    let options = RenderOptions {
+        emoji: EmojiOptions {
+            enabled: true,
+            image_url: Some("https://cdn.example.com/{unicode}.png?n={name}".to_string()),
+        },
+        ..Default::default()
+    };
+    let html = render_markdown_with_options("Nice :+1: :hash:", &options);
+    assert_eq!(
+        html,
+        concat!(
+            "<p>Nice <img class=\"emoji\" title=\":+1:\" alt=\"👍\" ",
+            "src=\"https://cdn.example.com/1f44d.png?n=+1\" height=\"20\" width=\"20\"> ",
+            "<img class=\"emoji\" title=\":hash:\" alt=\"#\u{FE0F}\u{20E3}\" ",
+            "src=\"https://cdn.example.com/23-20e3.png?n=hash\" height=\"20\" width=\"20\"></p>\n",
+        )
+    );
+}
+
+#[test]
+fn test_emoji_options_from_json() {
+    let options = RenderOptions::from_json(r#"{"emoji": {"enabled": true}}"#).unwrap();
+    let html = render_markdown_with_options("**:sparkles: new**", &options);
+    assert_eq!(html, "<p><strong>✨ new</strong></p>\n");
+}
@@ -0,0 +1,109 @@
+use awsm_markdown_renderer::{RenderOptions, render_markdown_with_options};
+
+mod common;
+
+fn extension_options() -> RenderOptions {
+    RenderOptions {
+        definition_lists: true,
+        superscript: true,
+        subscript: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_definition_lists() {
+    let html = render_markdown_with_options(
+        "HTML\n: HyperText Markup Language\n\nMathML $x^2$\n: First **definition**\n: Second with $y_1$\n",
+        &extension_options(),
+    );
+    assert!(
+        html.starts_with(
+            "<dl>\n<dt>HTML</dt>\n<dd>HyperText Markup Language</dd>\n<dt>MathML <math"
+        ),
+        "got: {}",
+        html
+    );
+    assert!(html.contains("<dd>First <strong>definition</strong></dd>"));
+    assert!(html.contains("<dd>Second with <math"));
+    assert!(html.contains("<msub><mi>y</mi><mn>1</mn></msub>"));
+    assert!(html.trim_end().ends_with("</dl>"));
+}
+
+#[test]
+fn test_superscript_subscript_and_strikethrough() {
+    let html = render_markdown_with_options("E = mc ^2^, x ~i~ and ~~old~~", &extension_options());
+    assert_eq!(
+        html,
+        "<p>E = mc <sup>2</sup>, x <sub>i</sub> and <del>old</del></p>\n"
+    );
+
+    // 只开启上标时，单个 `~` 仍是删除线
+    let options = RenderOptions {
+        superscript: true,
+        ..Default::default()
+    };
+    let html = render_markdown_with_options("^up^ ~down~ ~~del~~", &options);
+    assert_eq!(html, "<p><sup>up</sup> <del>down</del> <del>del</del></p>\n");
+}
+
+#[test]
+fn test_math_is_not_split_by_tilde_or_caret() {
+    for parser in common::PARSERS {
+        let mut options = extension_options();
+        options.math.parser = parser;
+
+        let html = render_markdown_with_options("$x ^2 + y^ 2$ and ^up^", &options);
+        assert!(!html.contains("<sup>2"), "{:?} got: {}", parser, html);
+        assert_eq!(html.matches("<msup>").count(), 2);
+        assert!(html.contains("<sup>up</sup>"));
+
+        let html = render_markdown_with_options("$a ~b$ and $c~ d$, $a~~b$ or $c~~d$", &options);
+        assert!(
+            !html.contains("<sub>") && !html.contains("<del>"),
+            "{:?} got: {}",
+            parser,
+            html
+        );
+        assert_eq!(html.matches("<math").count(), 4);
+
+        let html = render_markdown_with_options("$$\nx ^{2} + y^ {3}\n$$", &options);
+        assert!(
+            html.contains(r#"display="block""#),
+            "{:?} got: {}",
+            parser,
+            html
+        );
+        assert_eq!(
+            html.matches("<msup>").count(),
+            2,
+            "{:?} got: {}",
+            parser,
+            html
+        );
+    }
+}
+
+#[test]
+fn test_non_ascii_text_after_dollar_with_tilde_or_caret() {
+    for parser in common::PARSERS {
+        let mut options = extension_options();
+        options.math.parser = parser;
+
+        for input in [
+            "costs $5 in Übung ~sub~",
+            "A café costs $5 — ^up^",
+            "咖啡 $5 元 \"quoted\" -- ok...",
+        ] {
+            let html = render_markdown_with_options(input, &options);
+            assert_eq!(
+                html.matches("<math").count(),
+                0,
+                "{:?} got: {}",
+                parser,
+                html
+            );
+            assert!(html.contains("$5"), "{:?} got: {}", parser, html);
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use awsm_markdown_renderer::{RenderOptions, render_markdown_with_options};
+
+fn attribute_options(heading_ids: bool) -> RenderOptions {
+    RenderOptions {
+        heading_attributes: true,
+        heading_ids,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_heading_id_and_classes() {
+    let html =
+        render_markdown_with_options("## Install {#install .tabbed}", &attribute_options(false));
+    assert_eq!(html, "<h2 id=\"install\" class=\"tabbed\">Install</h2>\n");
+
+    let html = render_markdown_with_options("# Setup {.a .b data-x=1}", &attribute_options(false));
+    assert_eq!(html, "<h1 class=\"a b\" data-x=\"1\">Setup</h1>\n");
+}
+
+#[test]
+fn test_explicit_ids_take_precedence_over_slugs() {
+    let html = render_markdown_with_options(
+        "# Getting Started {#start}\n\n## $x^2$ {#square}",
+        &attribute_options(true),
+    );
+    assert!(
+        html.contains("<h1 id=\"start\">Getting Started</h1>"),
+        "got: {}",
+        html
+    );
+    assert!(html.contains("<h2 id=\"square\"><math"));
+
+    // 属性块不参与 slug
+    let html = render_markdown_with_options("# Setup {.wide}", &attribute_options(true));
+    assert_eq!(html, "<h1 id=\"setup\" class=\"wide\">Setup</h1>\n");
+}
+
+#[test]
+fn test_auto_slugs_avoid_explicit_ids() {
+    // 显式 id 出现在后面时，前面的自动 slug 也不会与之重复
+    let html = render_markdown_with_options(
+        "## Install {#install}\n\n# Install\n\n# Install\n\n# Other {#install-1}",
+        &attribute_options(true),
+    );
+    assert_eq!(
+        html,
+        concat!(
+            "<h2 id=\"install\">Install</h2>\n",
+            "<h1 id=\"install-2\">Install</h1>\n",
+            "<h1 id=\"install-3\">Install</h1>\n",
+            "<h1 id=\"install-1\">Other</h1>\n",
+        )
+    );
+}
@@ -1,4 +1,8 @@
-use awsm_markdown_renderer::render_markdown;
+use awsm_markdown_renderer::{
+    EmojiOptions, ReferenceOptions, RenderOptions, render_markdown, render_markdown_with_options,
+};
+
+mod common;
 
 #[test]
 fn test_basic_markdown() {
@@ -363,3 +367,149 @@ const App: React.FC<Props> = (props) => {
     // 检查泛型语法 <Props>
     assert!(html.contains("Props"), "Should contain 'Props' type name");
 }
+
+/// 可选功能在默认选项下不改变输出：(输入, 默认输出)
+const OFF_BY_DEFAULT: &[(&str, &str)] = &[
+    // 缩写
+    (
+        "HTML\n\n*[HTML]: Hyper Text Markup Language",
+        "<p>HTML</p>\n<p>*[HTML]: Hyper Text Markup Language</p>\n",
+    ),
+    // emoji 短代码
+    ("Shipped :tada:", "<p>Shipped :tada:</p>\n"),
+    // 定义列表、上标与下标（GFM 中单个 `~` 也是删除线）
+    (
+        "Term\n: Definition\n\n^sup^ ~sub~ ~~del~~",
+        "<p>Term\n: Definition</p>\n<p>^sup^ <del>sub</del> <del>del</del></p>\n",
+    ),
+    // 标题属性
+    (
+        "## Install {#install .tabbed}",
+        "<h2>Install {#install .tabbed}</h2>\n",
+    ),
+    // @user、#123 与提交 SHA
+    ("@alice #1 a5c3785", "<p>@alice #1 a5c3785</p>\n"),
+    // 智能标点
+    (
+        r#"He said "hi" -- ok..."#,
+        "<p>He said \"hi\" -- ok...</p>\n",
+    ),
+    // Wiki 链接
+    ("See [[Home]]", "<p>See [[Home]]</p>\n"),
+];
+
+#[test]
+fn test_optional_features_are_off_by_default() {
+    for (input, expected) in OFF_BY_DEFAULT {
+        assert_eq!(render_markdown(input), *expected, "input: {:?}", input);
+    }
+}
+
+// 文本替换类功能（自动链接、引用、emoji、缩写）不处理代码、公式与链接中的文本：
+// (选项, 输入, 输出中必须包含的片段, 输出中不能包含的片段)
+type TextFeatureCase = (
+    RenderOptions,
+    &'static str,
+    &'static [&'static str],
+    &'static [&'static str],
+);
+
+fn text_feature_cases() -> Vec<TextFeatureCase> {
+    vec![
+        (
+            RenderOptions::default(),
+            "`www.a.com` $\\text{www.b.com}$ [www.c.com](http://x.org) <a href=\"y\">www.d.com</a> ![www.e.com](e.png)\n\n```\nhttp://f.com\n```",
+            &[
+                "<code>www.a.com</code>",
+                "<a href=\"http://x.org\">www.c.com</a>",
+                "<a href=\"y\">www.d.com</a>",
+                "alt=\"www.e.com\"",
+                "http://f.com\n</span></code></pre>",
+            ],
+            &["www.b.com</a>", "href=\"http://f.com\""],
+        ),
+        (
+            RenderOptions {
+                references: ReferenceOptions {
+                    user_url: Some("https://example.com/users/{user}".to_string()),
+                    issue_url: Some("https://example.com/{repo}/issues/{number}".to_string()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            "`@alice #1` $\\#1$ [#5](u) foo@bar.com www.example.com/#3\n\n```\n@bob #2\n```",
+            &[
+                "<code>@alice #1</code>",
+                "<a href=\"u\">#5</a>",
+                "<a href=\"mailto:foo@bar.com\">foo@bar.com</a>",
+            ],
+            &["user-mention", "issue-link"],
+        ),
+        (
+            RenderOptions {
+                emoji: EmojiOptions {
+                    enabled: true,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            "`:tada:` $a :x: b$ [:tada:](https://example.com) https://example.com/:tada:/x :tada:\n\n```\n:tada:\n```",
+            &[
+                "<code>:tada:</code>",
+                ">:tada:</a>",
+                "href=\"https://example.com/:tada:/x\"",
+                "<mi>x</mi>",
+                " 🎉</p>",
+                "<span class=\"text plain\">:tada:\n</span>",
+            ],
+            &["❌"],
+        ),
+        (
+            RenderOptions {
+                abbreviations: true,
+                ..Default::default()
+            },
+            "*[API]: Application Programming Interface\n\n`API` $API$ [API](https://example.com) API\n\n```\nAPI\n*[SDK]: kept\n```",
+            &[
+                "<code>API</code>",
+                "<mi>A</mi>",
+                "<a href=\"https://example.com\">API</a>",
+                " <abbr title=\"Application Programming Interface\">API</abbr></p>",
+                "*[SDK]: kept",
+            ],
+            &[
+                "<code><abbr",
+                "<abbr title=\"Application Programming Interface\">API</abbr></a>",
+                "<abbr>SDK",
+            ],
+        ),
+    ]
+}
+
+#[test]
+fn test_text_features_skip_code_math_and_links() {
+    for parser in common::PARSERS {
+        for (mut options, input, present, absent) in text_feature_cases() {
+            options.math.parser = parser;
+            let html = render_markdown_with_options(input, &options);
+            for fragment in present {
+                assert!(
+                    html.contains(fragment),
+                    "{:?} {:?} got: {}",
+                    parser,
+                    fragment,
+                    html
+                );
+            }
+            for fragment in absent {
+                assert!(
+                    !html.contains(fragment),
+                    "{:?} {:?} got: {}",
+                    parser,
+                    fragment,
+                    html
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use awsm_markdown_renderer::{
+    MathAltText, MathOptions, MathOutput, MathParser, RenderOptions, render_markdown,
+    render_markdown_with_options,
+};
+
+fn alt_text(alt_text: MathAltText) -> RenderOptions {
+    RenderOptions {
+        math: MathOptions {
+            alt_text,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+// 提取第一个 <math> 的 aria-label
+fn aria_label(html: &str) -> &str {
+    let start = html.find("aria-label=\"").expect("aria-label missing") + "aria-label=\"".len();
+    let end = start + html[start..].find('"').unwrap();
+    &html[start..end]
+}
+
+fn speech(latex: &str) -> String {
+    // Native 模式下公式中的 `_`、`\,` 不会被当作 Markdown 语法
+    let mut options = alt_text(MathAltText::Speech);
+    options.math.parser = MathParser::Native;
+    let html = render_markdown_with_options(&format!("${}$", latex), &options);
+    aria_label(&html).to_string()
+}
+
+#[test]
+fn test_no_alt_text_by_default() {
+    let html = render_markdown("$x^2$");
+    assert!(!html.contains("alttext"));
+    assert!(!html.contains("aria-label"));
+}
+
+#[test]
+fn test_tex_alt_text_inline_and_display() {
+    let options = alt_text(MathAltText::Tex);
+
+    let html = render_markdown_with_options("Inline $a > b$ here", &options);
+    assert!(
+        html.contains(r#"<math xmlns="http://www.w3.org/1998/Math/MathML" display="inline" alttext="a &gt; b" aria-label="a &gt; b">"#),
+        "got: {}",
+        html
+    );
+
+    let html = render_markdown_with_options("$$\n\\frac{1}{2}\n$$", &options);
+    assert!(
+        html.contains(r#"display="block" alttext="\frac{1}{2}" aria-label="\frac{1}{2}">"#),
+        "got: {}",
+        html
+    );
+
+    let html = render_markdown_with_options("```math\nx^2\n```", &options);
+    assert!(html.contains(r#"alttext="x^2""#), "got: {}", html);
+}
+
+#[test]
+fn test_speech_alt_text_inline_and_display() {
+    let options = alt_text(MathAltText::Speech);
+
+    let html = render_markdown_with_options("Inline $x^2 + y^2 = r^2$ here", &options);
+    assert!(
+        html.contains(r#"display="inline" alttext="x squared plus y squared equals r squared""#),
+        "got: {}",
+        html
+    );
+    assert_eq!(
+        aria_label(&html),
+        "x squared plus y squared equals r squared"
+    );
+
+    let html =
+        render_markdown_with_options("$$\n\\sum_{i=1}^{n} i = \\frac{n(n+1)}{2}\n$$", &options);
+    assert!(html.contains(r#"display="block""#));
+    assert_eq!(
+        aria_label(&html),
+        "the sum from i equals 1 to n of i equals the fraction n open paren n plus 1 close paren over 2, end fraction"
+    );
+}
+
+#[test]
+fn test_speech_rules() {
+    let cases = [
+        (r"\frac{a}{b}", "a over b"),
+        (r"e^{i\pi}", "e to the power of i pi, end power"),
+        (r"x^n", "x to the power of n"),
+        (r"x_i", "x sub i"),
+        (r"\sqrt{2}", "the square root of 2"),
+        (r"\sqrt[3]{x+1}", "the cube root of x plus 1, end root"),
+        (r"\int_0^1 x\,dx", "the integral from 0 to 1 of x d x"),
+        (
+            r"\lim_{x \to 0} f(x)",
+            "the limit as x to 0 of f open paren x close paren",
+        ),
+        (r"\alpha \le \beta", "alpha is less than or equal to beta"),
+        (r"x \in \mathbb{R}", "x is in the real numbers"),
+        (r"\left( a \right)", "open paren a close paren"),
+        (r"\text{if } x", "if x"),
+        (r"\unknowncommand", "unknowncommand"),
+    ];
+    for (latex, expected) in cases {
+        assert_eq!(speech(latex), expected, "speech for {:?}", latex);
+    }
+}
+
+#[test]
+fn test_alt_text_in_native_mode_and_with_annotation() {
+    let mut options = alt_text(MathAltText::Speech);
+    options.math.parser = MathParser::Native;
+    options.math.output = MathOutput::MathmlWithTexAnnotation;
+
+    let html = render_markdown_with_options("$a*b$", &options);
+    assert_eq!(aria_label(&html), "a times b");
+    assert!(html.contains("<semantics>"));
+}
+
+#[test]
+fn test_alt_text_from_json_options() {
+    let options = RenderOptions::from_json(r#"{"math": {"altText": "speech"}}"#).unwrap();
+    assert_eq!(options.math.alt_text, MathAltText::Speech);
+}
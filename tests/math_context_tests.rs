@@ -0,0 +1,158 @@
+use awsm_markdown_renderer::{
+    MathDelimiters, MathOptions, MathParser, RenderOptions, render_markdown,
+    render_markdown_with_options,
+};
+
+mod common;
+
+fn options(parser: MathParser) -> RenderOptions {
+    RenderOptions {
+        heading_ids: true,
+        math: MathOptions {
+            parser,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_heading_ids_are_off_by_default() {
+    let html = render_markdown("# Energy $E=mc^2$");
+    assert!(html.contains("<h1>Energy <math"), "got: {}", html);
+}
+
+#[test]
+fn test_heading_slug_uses_latex_source() {
+    for parser in common::PARSERS {
+        let html = render_markdown_with_options("# Energy $E=mc^2$", &options(parser));
+        assert!(
+            html.contains(r#"<h1 id="energy-emc2">Energy <math"#),
+            "{:?} got: {}",
+            parser,
+            html
+        );
+        assert!(html.contains("<msup><mi>c</mi><mn>2</mn></msup>"));
+    }
+}
+
+#[test]
+fn test_heading_slugs() {
+    let cases = [
+        ("# Hello World", "hello-world"),
+        ("## `code` and *emphasis*", "code-and-emphasis"),
+        ("### C++ & Rust_lang!", "c--rust_lang"),
+        ("# 数学公式 $x$", "数学公式-x"),
+        ("Setext $\\alpha$\n===", "setext-alpha"),
+    ];
+    for parser in common::PARSERS {
+        for (input, slug) in cases {
+            let html = render_markdown_with_options(input, &options(parser));
+            assert!(
+                html.contains(&format!(r#" id="{}""#, slug)),
+                "{:?}: {:?} should have id {:?}, got: {}",
+                parser,
+                input,
+                slug,
+                html
+            );
+        }
+    }
+}
+
+#[test]
+fn test_duplicate_heading_slugs_get_suffixes() {
+    let html = render_markdown_with_options(
+        "# Intro\n\n## Intro\n\n## Intro-1\n\n## Intro",
+        &options(MathParser::Regex),
+    );
+    let ids: Vec<&str> = html
+        .match_indices(" id=\"")
+        .map(|(start, _)| {
+            let rest = &html[start + 5..];
+            &rest[..rest.find('"').unwrap()]
+        })
+        .collect();
+    assert_eq!(ids, ["intro", "intro-1", "intro-1-1", "intro-2"]);
+}
+
+#[test]
+fn test_heading_slug_with_extra_delimiters() {
+    let mut options = options(MathParser::Regex);
+    options.math.delimiters = MathDelimiters {
+        parens: true,
+        ..Default::default()
+    };
+    let html = render_markdown_with_options(r"# Root \(\sqrt{x}\)", &options);
+    assert!(html.contains(r#"<h1 id="root-sqrtx">"#), "got: {}", html);
+    assert!(html.contains("<msqrt>"));
+}
+
+#[test]
+fn test_pipes_inside_math_in_table_cells() {
+    let input = "| a | b |\n|---|---|\n| $\\left|x\\right|$ | $a|b$ |\n| $|y|$ | text |";
+    for parser in common::PARSERS {
+        let html = render_markdown_with_options(input, &options(parser));
+        assert_eq!(
+            html.matches("<td>").count(),
+            4,
+            "{:?} got: {}",
+            parser,
+            html
+        );
+        assert_eq!(html.matches("<math").count(), 3);
+        assert!(html.contains("<mi>a</mi><mo>|</mo><mi>b</mi>"));
+        assert!(html.contains("<td>text</td>"));
+    }
+}
+
+#[test]
+fn test_currency_in_table_cells_stays_text() {
+    let input = "| item | price |\n|---|---|\n| a | $5 |\n| b | $10 |";
+    let html = render_markdown(input);
+    assert_eq!(html.matches("<math").count(), 0, "got: {}", html);
+    assert!(html.contains("<td>$5</td>"));
+    assert!(html.contains("<td>$10</td>"));
+}
+
+#[test]
+fn test_non_ascii_text_after_dollar_with_pipes() {
+    // 扫描 `|` 相关公式时按字节查找结束分隔符，不能在多字节字符中间切分
+    let cases = [
+        ("costs $5 in C:\\Übung | x", "Übung"),
+        ("A café costs $5 — cheap | x", "— cheap"),
+        (
+            "| 商品 | 价格 |\n|---|---|\n| 咖啡 | $5 元 |",
+            "<td>$5 元</td>",
+        ),
+        ("| a |\n|---|\n| $|x|$ 和 $5 元 |", "和 $5 元"),
+    ];
+    for parser in common::PARSERS {
+        for (input, expected) in cases {
+            let html = render_markdown_with_options(input, &options(parser));
+            assert!(html.contains(expected), "{:?} got: {}", parser, html);
+        }
+    }
+}
+
+#[test]
+fn test_pipes_inside_code_in_table_are_untouched() {
+    let input = "| a |\n|---|\n| `$a|b$` |";
+    let html = render_markdown(input);
+    assert_eq!(html.matches("<math").count(), 0, "got: {}", html);
+}
+
+#[test]
+fn test_math_in_link_text() {
+    for parser in common::PARSERS {
+        let html =
+            render_markdown_with_options("[$|x|$ norm](http://example.com)", &options(parser));
+        assert!(
+            html.contains(r#"<a href="http://example.com"><math"#),
+            "{:?} got: {}",
+            parser,
+            html
+        );
+        assert!(html.contains("</math> norm</a>"));
+    }
+}
@@ -0,0 +1,125 @@
+use awsm_markdown_renderer::{
+    MathOptions, MathParser, RenderOptions, render_markdown_with_options,
+};
+
+mod common;
+
+fn native_options(github_boundaries: bool) -> RenderOptions {
+    RenderOptions {
+        math: MathOptions {
+            parser: MathParser::Native,
+            github_boundaries,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// 疑难用例语料：(输入, 期望的公式数量, 输出中必须包含的片段, 输出中不能包含的片段)
+const CORPUS: &[(&str, usize, &[&str], &[&str])] = &[
+    // 公式内的 * 和 _ 不能被当作强调
+    ("$a*b*c$", 1, &[], &["<em>"]),
+    ("$x_1$ and $y_1$", 2, &[" and "], &["<em>"]),
+    (
+        "$a_1 + b_1$ and *real* emphasis",
+        1,
+        &["<em>real</em>"],
+        &[],
+    ),
+    // 转义的 \$ 是普通美元符号
+    (r"\$5 and $x$", 1, &["$5 and "], &[]),
+    (r"price: \$10 \$20", 0, &["$10 $20"], &[]),
+    // 货币金额
+    (
+        "The costs are $5 and $10 respectively.",
+        0,
+        &["$5", "$10"],
+        &[],
+    ),
+    ("$1/day $5/month", 0, &["$1/day $5/month"], &[]),
+    // 公式内的反斜杠、括号与管道符保持原样交给 LaTeX
+    (r"$\{a, b\}$", 1, &[], &["math-error"]),
+    (r"$\left| x \right|$", 1, &[], &["math-error"]),
+    // 格式与链接内的公式
+    ("**$E=mc^2$**", 1, &["<strong>"], &[]),
+    (
+        "[$x$](http://example.com)",
+        1,
+        &["<a href=\"http://example.com\">"],
+        &[],
+    ),
+    // 中文紧邻公式
+    ("公式$x+y$的计算结果。", 1, &["公式", "的计算结果"], &[]),
+    // 行内与块级 $$
+    ("$$x^2$$ inline", 1, &["display=\"block\"", " inline"], &[]),
+    ("$$\n\\int_0^1 x\\,dx\n$$", 1, &["display=\"block\""], &[]),
+    // 空白包围的 $ 不是公式
+    ("$ x $", 0, &["$ x $"], &[]),
+    // 代码中的 $ 保持原样
+    ("`$x$`", 0, &["<code>$x$</code>"], &[]),
+];
+
+#[test]
+fn test_native_math_corpus() {
+    let options = native_options(true);
+
+    for (input, expected_math, must_contain, must_not_contain) in CORPUS {
+        let html = render_markdown_with_options(input, &options);
+
+        assert_eq!(
+            common::count_math(&html),
+            *expected_math,
+            "Unexpected formula count for {:?}, got: {}",
+            input,
+            html
+        );
+        for fragment in *must_contain {
+            assert!(
+                html.contains(fragment),
+                "Output for {:?} should contain {:?}, got: {}",
+                input,
+                fragment,
+                html
+            );
+        }
+        for fragment in *must_not_contain {
+            assert!(
+                !html.contains(fragment),
+                "Output for {:?} should not contain {:?}, got: {}",
+                input,
+                fragment,
+                html
+            );
+        }
+    }
+}
+
+#[test]
+fn test_native_math_github_boundaries() {
+    // 开启 GitHub 边界规则：$ 外侧紧贴字母或数字时保留原文
+    let html = render_markdown_with_options("a$x$b and $y$5", &native_options(true));
+    assert_eq!(common::count_math(&html), 0, "got: {}", html);
+    assert!(html.contains("a$x$b"));
+    assert!(html.contains("$y$5"));
+
+    // 关闭后使用 pulldown-cmark 自身的规则
+    let html = render_markdown_with_options("a$x$b and $y$5", &native_options(false));
+    assert_eq!(common::count_math(&html), 2, "got: {}", html);
+}
+
+#[test]
+fn test_native_math_keeps_fenced_math_blocks() {
+    let html = render_markdown_with_options("```math\nE=mc^2\n```", &native_options(true));
+    assert!(html.contains("display=\"block\""), "got: {}", html);
+}
+
+#[test]
+fn test_native_math_from_json_options() {
+    let options = RenderOptions::from_json(r#"{"math": {"parser": "native"}}"#).unwrap();
+    assert_eq!(options.math.parser, MathParser::Native);
+    assert!(options.math.github_boundaries);
+
+    let html = render_markdown_with_options("$a*b*c$", &options);
+    assert_eq!(common::count_math(&html), 1);
+    assert!(!html.contains("<em>"));
+}
@@ -0,0 +1,139 @@
+use awsm_markdown_renderer::{
+    MathDelimiters, MathOptions, MathParser, RenderOptions, render_markdown,
+    render_markdown_with_options,
+};
+
+mod common;
+
+fn options_with(parser: MathParser, delimiters: MathDelimiters) -> RenderOptions {
+    RenderOptions {
+        math: MathOptions {
+            parser,
+            delimiters,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+fn all_delimiters() -> MathDelimiters {
+    MathDelimiters {
+        parens: true,
+        brackets: true,
+        gitlab: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_backslash_delimiters_disabled_by_default() {
+    let html = render_markdown(r"Inline \(x^2\) here");
+    assert_eq!(common::count_math(&html), 0);
+    assert!(html.contains("(x^2)"), "got: {}", html);
+}
+
+#[test]
+fn test_paren_and_bracket_delimiters() {
+    for parser in common::PARSERS {
+        let options = options_with(parser, all_delimiters());
+
+        let html = render_markdown_with_options(r"Inline \(a_1 * b_1\) here", &options);
+        assert_eq!(common::count_math(&html), 1, "got: {}", html);
+        assert!(!html.contains("display=\"block\""));
+        assert!(
+            !html.contains("<em>"),
+            "Math content must not be parsed as Markdown"
+        );
+        assert!(html.contains("Inline ") && html.contains(" here"));
+
+        let html = render_markdown_with_options("\\[\n\\sum_{i=1}^n i\n\\]", &options);
+        assert_eq!(common::count_math(&html), 1, "got: {}", html);
+        assert!(html.contains("display=\"block\""));
+    }
+}
+
+#[test]
+fn test_gitlab_dollar_backtick_delimiter() {
+    for parser in common::PARSERS {
+        let options = options_with(parser, all_delimiters());
+
+        let html = render_markdown_with_options("Formula $`a^2+b^2`$ and `code`", &options);
+        assert_eq!(common::count_math(&html), 1, "got: {}", html);
+        assert!(html.contains("<code>code</code>"));
+        assert!(!html.contains("<code>a^2+b^2</code>"));
+    }
+}
+
+#[test]
+fn test_delimiters_independently_toggleable() {
+    let delimiters = MathDelimiters {
+        parens: true,
+        ..Default::default()
+    };
+    let html = render_markdown_with_options(
+        r"\(x\) and \[y\] and $`z`$",
+        &options_with(MathParser::Regex, delimiters),
+    );
+    assert_eq!(
+        common::count_math(&html),
+        1,
+        "Only \\(...\\) is enabled, got: {}",
+        html
+    );
+
+    // 关闭 $ 与 $$
+    let delimiters = MathDelimiters {
+        dollars: false,
+        double_dollars: false,
+        ..Default::default()
+    };
+    for parser in common::PARSERS {
+        let html = render_markdown_with_options(
+            "$x$ and $$y$$",
+            &options_with(parser, delimiters.clone()),
+        );
+        assert_eq!(common::count_math(&html), 0, "got: {}", html);
+        assert!(html.contains("$x$ and $$y$$"));
+    }
+}
+
+#[test]
+fn test_delimiters_ignored_in_code_and_escapes() {
+    let options = options_with(MathParser::Regex, all_delimiters());
+
+    let input = "`\\(x\\)`\n\n```\n\\[y\\]\n```\n\nEscaped \\\\(z\\\\)";
+    let html = render_markdown_with_options(input, &options);
+    assert_eq!(common::count_math(&html), 0, "got: {}", html);
+    assert!(html.contains("<code>\\(x\\)</code>"));
+    assert!(html.contains("\\[y\\]"));
+}
+
+#[test]
+fn test_delimiters_keep_currency_rules() {
+    let options = options_with(MathParser::Regex, all_delimiters());
+
+    let html = render_markdown_with_options(r"\(x\) costs $5 and $10 respectively.", &options);
+    assert_eq!(common::count_math(&html), 1, "got: {}", html);
+    assert!(html.contains("$5") && html.contains("$10"));
+}
+
+#[test]
+fn test_delimiters_do_not_span_paragraphs() {
+    let options = options_with(MathParser::Regex, all_delimiters());
+
+    let html = render_markdown_with_options("\\(x\n\ny\\)", &options);
+    assert_eq!(common::count_math(&html), 0, "got: {}", html);
+}
+
+#[test]
+fn test_delimiters_from_json_options() {
+    let options = RenderOptions::from_json(
+        r#"{"math": {"delimiters": {"brackets": true, "dollars": false}}}"#,
+    )
+    .unwrap();
+    let delimiters = &options.math.delimiters;
+    assert!(delimiters.brackets);
+    assert!(!delimiters.dollars);
+    assert!(delimiters.double_dollars);
+    assert!(!delimiters.parens);
+}
@@ -0,0 +1,134 @@
+use awsm_markdown_renderer::{
+    DollarRule, MathDelimiters, MathOptions, MathParser, RenderOptions, render_markdown,
+    render_markdown_with_options,
+};
+
+mod common;
+
+fn options(parser: MathParser, dollar_rule: DollarRule) -> RenderOptions {
+    RenderOptions {
+        math: MathOptions {
+            parser,
+            dollar_rule,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// 各规则下的公式数量：(输入, Github, Pandoc, Strict)
+const RULES: &[(&str, usize, usize, usize)] = &[
+    // 货币金额
+    ("costs $5 and $10", 0, 0, 0),
+    ("costs $5, or $x$ here", 1, 1, 0),
+    ("$20,000 and $x$", 1, 1, 0),
+    ("$1/day $5/month", 0, 0, 0),
+    // 转义的 \$ 总是普通美元符号
+    (r"\$5 and \$10", 0, 0, 0),
+    (r"\$x\$", 0, 0, 0),
+    (r"\$x$ and $y$", 1, 1, 0),
+    (r"$a \$ b$", 1, 1, 0),
+    // `$` 内侧不能是空白
+    ("$ x$", 0, 0, 0),
+    ("$x $", 0, 0, 0),
+    // 外侧字符：Github 要求不紧贴字母数字，Pandoc 只要求结束的 `$` 后面不是数字
+    ("a$x$b", 0, 1, 0),
+    ("$x$5", 0, 0, 0),
+    ("$x$.", 1, 1, 0),
+    // $$ 在所有规则下都是公式
+    ("$$x^2$$", 1, 1, 1),
+    ("before $$x$$ after", 1, 1, 1),
+    ("$$\nx^2\n$$", 1, 1, 1),
+    ("```math\nx^2\n```", 1, 1, 1),
+];
+
+#[test]
+fn test_dollar_rules_in_both_parsers() {
+    for parser in common::PARSERS {
+        for (input, github, pandoc, strict) in RULES {
+            for (rule, expected) in [
+                (DollarRule::Github, github),
+                (DollarRule::Pandoc, pandoc),
+                (DollarRule::Strict, strict),
+            ] {
+                let html = render_markdown_with_options(input, &options(parser, rule));
+                assert_eq!(
+                    common::count_math(&html),
+                    *expected,
+                    "{:?} / {:?}: unexpected formula count for {:?}, got: {}",
+                    parser,
+                    rule,
+                    input,
+                    html
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_escaped_dollar_is_literal_text() {
+    for parser in common::PARSERS {
+        let html =
+            render_markdown_with_options(r"\$5 and \$10", &options(parser, DollarRule::Github));
+        assert_eq!(html.trim(), "<p>$5 and $10</p>", "{:?}", parser);
+
+        // 转义的 `\$` 不会结束公式，交给 LaTeX 作为美元符号
+        let html = render_markdown_with_options(r"$a \$ b$", &options(parser, DollarRule::Github));
+        assert!(
+            html.contains(r#"<mi mathvariant="normal">$</mi>"#),
+            "{:?} got: {}",
+            parser,
+            html
+        );
+    }
+}
+
+#[test]
+fn test_rejected_opening_dollar_does_not_swallow_formula() {
+    // `$5, or $` 不是公式，但后面的 `$x$` 仍然是
+    let html = render_markdown("costs $5, or $x$ here");
+    assert!(html.contains("costs $5, or <math"), "got: {}", html);
+    assert!(html.contains("</math> here"));
+}
+
+#[test]
+fn test_regex_mode_keeps_latex_escapes() {
+    // Markdown 转义（`\{`、`\,`、`\\`）在 Regex 模式下的公式中保持为 LaTeX 源码
+    let html = render_markdown(r"$\{a, b\}$");
+    assert!(html.contains("<mo>{</mo>"), "got: {}", html);
+    assert!(!html.contains("math-error"));
+
+    let html = render_markdown(r"$x_1$ and $y_1$");
+    assert_eq!(common::count_math(&html), 2, "got: {}", html);
+    assert!(!html.contains("<em>"));
+
+    // 公式之外的转义与原来一样
+    let html = render_markdown(r"\*not emphasis\* and $x$");
+    assert!(html.contains("*not emphasis* and <math"), "got: {}", html);
+}
+
+#[test]
+fn test_strict_mode_still_honours_disabled_double_dollars() {
+    let mut options = options(MathParser::Regex, DollarRule::Strict);
+    options.math.delimiters = MathDelimiters {
+        double_dollars: false,
+        ..Default::default()
+    };
+    let html = render_markdown_with_options("$$x$$ and $y$", &options);
+    assert_eq!(common::count_math(&html), 0, "got: {}", html);
+}
+
+#[test]
+fn test_dollar_rule_from_json_options() {
+    let options = RenderOptions::from_json(r#"{"math": {"dollarRule": "pandoc"}}"#).unwrap();
+    assert_eq!(options.math.dollar_rule, DollarRule::Pandoc);
+
+    let options = RenderOptions::from_json(r#"{"math": {"dollarRule": "strict"}}"#).unwrap();
+    assert_eq!(options.math.dollar_rule, DollarRule::Strict);
+
+    assert_eq!(
+        RenderOptions::default().math.dollar_rule,
+        DollarRule::Github
+    );
+}
@@ -0,0 +1,203 @@
+use awsm_markdown_renderer::{
+    MathOptions, MathParser, RenderOptions, render_markdown, render_markdown_with_options,
+};
+
+fn native_options() -> RenderOptions {
+    RenderOptions {
+        math: MathOptions {
+            parser: MathParser::Native,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// 兼容性矩阵：(公式, 输出中必须依次出现的 MathML 片段)
+const COMPATIBILITY: &[(&str, &[&str])] = &[
+    // 对齐
+    (
+        r"\begin{align} a &= b \\ c &= d \end{align}",
+        &[
+            r#"<mtable columnalign="right left">"#,
+            r#"<mtd><mstyle displaystyle="true"><mi>a</mi></mstyle></mtd>"#,
+            "<mo>=</mo><mi>b</mi>",
+            "<mi>c</mi>",
+        ],
+    ),
+    (
+        r"\begin{align*} x &= 1 & y &= 2 \end{align*}",
+        &[r#"<mtable columnalign="right left right left">"#],
+    ),
+    (
+        r"\begin{aligned} f(x) &= x^2 \\ &= x \cdot x \end{aligned}",
+        &[r#"<mtable columnalign="right left">"#, "<mo>·</mo>"],
+    ),
+    (
+        r"\begin{split} a &= b + c \\ &= d \end{split}",
+        &[r#"<mtable columnalign="right left">"#],
+    ),
+    (
+        r"\begin{alignat}{2} a &= b &\quad c &= d \end{alignat}",
+        &[r#"<mtable columnalign="right left right left">"#],
+    ),
+    // 居中
+    (
+        r"\begin{gather} a = b \\ c = d \end{gather}",
+        &[r#"<mtable columnalign="center">"#, "<mi>a</mi>", "<mi>c</mi>"],
+    ),
+    (
+        r"\begin{gathered} a \\ b \end{gathered}",
+        &[r#"<mtable columnalign="center">"#],
+    ),
+    (
+        r"\begin{equation} E = mc^2 \end{equation}",
+        &["<mtable", "<mi>E</mi>", "<msup><mi>c</mi><mn>2</mn></msup>"],
+    ),
+    // 分段函数
+    (
+        r"\begin{cases} 1 & x > 0 \\ 0 & \text{otherwise} \end{cases}",
+        &[
+            r#"<mo stretchy="true" form="prefix">{</mo><mtable columnalign="left left">"#,
+            "<mn>1</mn>",
+            "<mtext>otherwise</mtext>",
+        ],
+    ),
+    (
+        r"\begin{dcases} \frac{1}{2} & x \end{dcases}",
+        &[
+            r#"form="prefix">{</mo>"#,
+            r#"<mstyle displaystyle="true"><mfrac>"#,
+        ],
+    ),
+    (
+        r"\begin{rcases} a & b \end{rcases}",
+        &["</mtable>", r#"<mo stretchy="true" form="postfix">}</mo>"#],
+    ),
+    // 矩阵
+    (
+        r"\begin{matrix} 1 & 2 \\ 3 & 4 \end{matrix}",
+        &[
+            r#"<mtable columnalign="center center">"#,
+            "<mtr><mtd><mn>1</mn></mtd><mtd><mn>2</mn></mtd></mtr>",
+            "<mtr><mtd><mn>3</mn></mtd><mtd><mn>4</mn></mtd></mtr>",
+        ],
+    ),
+    (
+        r"\begin{pmatrix} a & b \\ c & d \end{pmatrix}",
+        &[r#"form="prefix">(</mo>"#, "<mtable", r#"form="postfix">)</mo>"#],
+    ),
+    (
+        r"\begin{bmatrix} a \\ b \end{bmatrix}",
+        &[r#"form="prefix">[</mo>"#, r#"form="postfix">]</mo>"#],
+    ),
+    (
+        r"\begin{Bmatrix} a \end{Bmatrix}",
+        &[r#"form="prefix">{</mo>"#, r#"form="postfix">}</mo>"#],
+    ),
+    (
+        r"\begin{vmatrix} a & b \\ c & d \end{vmatrix}",
+        &[r#"form="prefix">|</mo>"#, r#"form="postfix">|</mo>"#],
+    ),
+    (
+        r"\begin{Vmatrix} a \end{Vmatrix}",
+        &[r#"form="prefix">‖</mo>"#, r#"form="postfix">‖</mo>"#],
+    ),
+    (
+        r"\begin{smallmatrix} a & b \end{smallmatrix}",
+        &[r#"<mtable columnalign="center center">"#],
+    ),
+    (
+        r"\begin{array}{lc|r} a & b & c \\ \hline d & e & f \end{array}",
+        &[
+            r#"<mtable columnalign="left center right">"#,
+            "<mi>c</mi></mtd></mtr><mtr><mtd><mi>d</mi>",
+        ],
+    ),
+    // 运算符名
+    (r"\operatorname{rank}(A)", &["<mi>rank</mi>", "<mo>(</mo>"]),
+    (
+        r"\operatorname*{argmax}_{x} f",
+        &["<munder><mrow><mi>argmax</mi></mrow><mi>x</mi></munder>"],
+    ),
+    (
+        r"\operatorname{Tr}^2 A",
+        &["<msup><mrow><mi>Tr</mi></mrow><mn>2</mn></msup>"],
+    ),
+    (r"\operatorname{lim\,sup}", &["<mi>lim\u{2009}sup</mi>"]),
+];
+
+#[test]
+fn test_environment_compatibility_matrix() {
+    let options = native_options();
+
+    for (latex, fragments) in COMPATIBILITY {
+        let html = render_markdown_with_options(&format!("$${}$$", latex), &options);
+
+        assert!(
+            !html.contains("PARSE ERROR") && !html.contains("math-error"),
+            "{} failed to render: {}",
+            latex,
+            html
+        );
+
+        let mut pos = 0;
+        for fragment in *fragments {
+            match html[pos..].find(fragment) {
+                Some(offset) => pos += offset + fragment.len(),
+                None => panic!(
+                    "{} should contain {:?} (in order), got: {}",
+                    latex, fragment, html
+                ),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_nested_environments() {
+    let html = render_markdown_with_options(
+        r"$$\begin{aligned} A &= \begin{pmatrix} 1 & 0 \\ 0 & 1 \end{pmatrix} \\ B &= 0 \end{aligned}$$",
+        &native_options(),
+    );
+    assert_eq!(html.matches("<mtable").count(), 2, "got: {}", html);
+    assert_eq!(html.matches("<mtr>").count(), 4);
+    assert!(!html.contains("PARSE ERROR"));
+}
+
+#[test]
+fn test_environment_as_script_base() {
+    let html = render_markdown_with_options(
+        r"$\begin{pmatrix} 1 & 2 \end{pmatrix}^T$",
+        &native_options(),
+    );
+    assert!(
+        html.contains(r#"<msup><mrow><mo stretchy="true" form="prefix">(</mo><mtable"#),
+        "got: {}",
+        html
+    );
+    assert!(html.contains("</mrow><mi>T</mi></msup>"));
+}
+
+#[test]
+fn test_environments_in_display_blocks_and_fences() {
+    let html = render_markdown("$$\n\\begin{cases}\n1 & x > 0 \\\\\n0 & x \\le 0\n\\end{cases}\n$$");
+    assert!(html.contains(r#"display="block""#), "got: {}", html);
+    assert_eq!(html.matches("<mtr>").count(), 2);
+
+    let html = render_markdown("```math\n\\begin{align}\na &= b \\\\\nc &= d\n\\end{align}\n```");
+    assert!(
+        html.contains(r#"<mtable columnalign="right left">"#),
+        "got: {}",
+        html
+    );
+    assert_eq!(html.matches("<mtr>").count(), 2);
+}
+
+#[test]
+fn test_unknown_environment_is_left_to_latex2mathml() {
+    let html = render_markdown_with_options(
+        r"$\begin{unknownenv} x \end{unknownenv}$",
+        &native_options(),
+    );
+    assert!(!html.contains("<mtable"), "got: {}", html);
+}
@@ -0,0 +1,230 @@
+use awsm_markdown_renderer::{
+    EquationNumbers, MathOptions, RenderOptions, render_markdown, render_markdown_with_metadata,
+    render_markdown_with_options,
+};
+
+fn numbering(equation_numbers: EquationNumbers) -> RenderOptions {
+    RenderOptions {
+        math: MathOptions {
+            equation_numbers,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_numbering_disabled_by_default() {
+    let html = render_markdown("$$\nx = 1\n$$\n\n$$\ny = 2\n$$");
+    assert!(!html.contains("math-equation"), "got: {}", html);
+}
+
+#[test]
+fn test_number_all_display_equations() {
+    let input =
+        "$$\na = 1\n$$\n\n$$\nb = 2 \\nonumber\n$$\n\n$$\nc = 3\n$$\n\nInline $d$ is not numbered.";
+    let html = render_markdown_with_options(input, &numbering(EquationNumbers::All));
+
+    assert_eq!(
+        html.matches("math-equation-number").count(),
+        2,
+        "got: {}",
+        html
+    );
+    assert!(html.contains(r#"<span class="math-equation" id="eq-1">"#));
+    assert!(html.contains(r#"<span class="math-equation-number">(1)</span>"#));
+    assert!(html.contains(r#"<span class="math-equation" id="eq-2">"#));
+    assert!(html.contains(r#"<span class="math-equation-number">(2)</span>"#));
+    assert!(!html.contains("nonumber"));
+    assert!(!html.contains("math-error"));
+}
+
+#[test]
+fn test_number_labeled_equations_and_tags() {
+    let input = r#"
+$$
+E = mc^2 \label{eq:energy}
+$$
+
+$$
+F = ma
+$$
+
+$$
+a^2 + b^2 = c^2 \tag{P}
+$$
+"#;
+    let html = render_markdown_with_options(input, &numbering(EquationNumbers::Labeled));
+
+    assert!(html.contains(r#"id="eq-eq:energy""#), "got: {}", html);
+    assert!(html.contains(r#"<span class="math-equation-number">(1)</span>"#));
+    assert!(html.contains(r#"<span class="math-equation-number">(P)</span>"#));
+    assert_eq!(html.matches("math-equation-number").count(), 2);
+    assert!(!html.contains("label"), "\\label must be removed: {}", html);
+}
+
+#[test]
+fn test_tag_shown_without_numbering() {
+    let html = render_markdown("$$\nx = 1 \\tag*{★}\n$$");
+    assert!(
+        html.contains(r#"<span class="math-equation-number">★</span>"#),
+        "got: {}",
+        html
+    );
+}
+
+#[test]
+fn test_references_in_text_resolve_forward_and_backward() {
+    let input = r#"By \eqref{eq:sum} we have the result, see also equation \ref{eq:sum}.
+
+$$
+\sum_{i=1}^n i = \frac{n(n+1)}{2} \label{eq:sum}
+$$
+
+Again \eqref{eq:sum}."#;
+    let result = render_markdown_with_metadata(input, &numbering(EquationNumbers::All));
+
+    assert_eq!(
+        result
+            .html
+            .matches(r##"<a class="math-ref" href="#eq-eq:sum">(1)</a>"##)
+            .count(),
+        2,
+        "got: {}",
+        result.html
+    );
+    assert!(
+        result
+            .html
+            .contains(r##"equation <a class="math-ref" href="#eq-eq:sum">1</a>."##)
+    );
+    assert!(result.unresolved_labels.is_empty());
+}
+
+#[test]
+fn test_references_inside_math() {
+    let input = r#"
+$$
+x = 2 \label{first}
+$$
+
+$$
+y = x + 1 \quad \text{by} \eqref{first}
+$$
+"#;
+    let html = render_markdown_with_options(input, &numbering(EquationNumbers::All));
+
+    assert!(
+        html.contains(r##"<mtext><a class="math-ref" href="#eq-first">(1)</a></mtext>"##),
+        "got: {}",
+        html
+    );
+}
+
+#[test]
+fn test_unresolved_labels_reported() {
+    let input = "See \\eqref{missing} and $x = \\ref{also-missing}$ and \\ref{missing}.";
+    let result = render_markdown_with_metadata(input, &numbering(EquationNumbers::All));
+
+    assert_eq!(result.unresolved_labels, vec!["missing", "also-missing"]);
+    assert!(
+        result
+            .html
+            .contains(r#"<span class="math-ref-missing">\eqref{missing}</span>"#),
+        "got: {}",
+        result.html
+    );
+    assert!(result.html.contains(r#"title="also-missing">??</span>"#));
+}
+
+#[test]
+fn test_references_not_resolved_in_code() {
+    let input = "`\\eqref{a}`\n\n```\n\\ref{a}\n```\n\n$$\nx \\label{a}\n$$";
+    let result = render_markdown_with_metadata(input, &numbering(EquationNumbers::All));
+
+    assert!(!result.html.contains("math-ref"), "got: {}", result.html);
+    assert!(result.html.contains(r"<code>\eqref{a}</code>"));
+}
+
+#[test]
+fn test_references_in_prose_without_equations_are_unchanged() {
+    let result = render_markdown_with_metadata(r"Use \ref{fig} here", &RenderOptions::default());
+    assert_eq!(result.html, "<p>Use \\ref{fig} here</p>\n");
+    assert!(result.unresolved_labels.is_empty());
+
+    // 有 `\label` 时即使不编号也会解析
+    let result = render_markdown_with_metadata(
+        "$$\nx \\label{a}\n$$\n\nSee \\ref{a} and \\ref{fig}",
+        &RenderOptions::default(),
+    );
+    assert!(
+        result
+            .html
+            .contains(r##"<a class="math-ref" href="#eq-a">"##),
+        "got: {}",
+        result.html
+    );
+    assert_eq!(result.unresolved_labels, ["fig"]);
+}
+
+#[test]
+fn test_private_use_characters_are_not_references() {
+    // 图标字体（Nerd Font）字符与不存在的占位符都保持原样
+    let input = "```\n\u{F0005} \u{E003}7\u{E004}\n```\n\n$$\nx \\label{a}\n$$\n\n$y = \\ref{a}$";
+    let result = render_markdown_with_metadata(input, &numbering(EquationNumbers::All));
+
+    assert!(
+        result.html.contains("\u{F0005} \u{E003}7\u{E004}"),
+        "got: {}",
+        result.html
+    );
+    assert_eq!(result.html.matches(r#"class="math-ref""#).count(), 1);
+}
+
+#[test]
+fn test_many_references_inside_math() {
+    let refs = [r"\ref{a}"; 12].join(", ") + r", \ref{a}^2";
+    let input = format!("$$\nx \\label{{a}}\n$$\n\n$$\n{}\n$$", refs);
+    let html = render_markdown_with_options(&input, &numbering(EquationNumbers::All));
+
+    assert_eq!(
+        html.matches(r##"<mtext><a class="math-ref" href="#eq-a">1</a></mtext>"##)
+            .count(),
+        13,
+        "got: {}",
+        html
+    );
+}
+
+#[test]
+fn test_references_from_extra_delimiters_tables_and_macros() {
+    let link = r##"<mtext><a class="math-ref" href="#eq-a">(1)</a></mtext>"##;
+
+    // 源文本中的公式在解析前已被替换为占位符
+    let mut options = numbering(EquationNumbers::All);
+    options.math.delimiters.parens = true;
+    options.math.delimiters.brackets = true;
+    let result = render_markdown_with_metadata(r"\[x \label{a}\] See \(\eqref{a}\).", &options);
+    assert!(result.html.contains(link), "got: {}", result.html);
+
+    let input = "$$\nx \\label{a}\n$$\n\n| a |\n|---|\n| $\\eqref{a}|x$ |";
+    let html = render_markdown_with_options(input, &numbering(EquationNumbers::All));
+    assert!(html.contains(link), "got: {}", html);
+
+    // 引用来自宏展开，源文本中没有 `ref`
+    let mut options = numbering(EquationNumbers::All);
+    options
+        .math
+        .macros
+        .insert(r"\er".to_string(), r"\eqref{#1}".to_string());
+    let result =
+        render_markdown_with_metadata("$$\nx \\label{a}\n$$\n\n$\\er{a}$ $\\er{b}$", &options);
+    assert!(result.html.contains(link), "got: {}", result.html);
+    assert_eq!(result.unresolved_labels, ["b"]);
+}
+
+#[test]
+fn test_equation_numbers_from_json_options() {
+    let options = RenderOptions::from_json(r#"{"math": {"equationNumbers": "labeled"}}"#).unwrap();
+    assert_eq!(options.math.equation_numbers, EquationNumbers::Labeled);
+}
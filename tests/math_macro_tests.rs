@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+
+use awsm_markdown_renderer::{
+    MathOptions, RenderOptions, render_markdown, render_markdown_with_options,
+};
+
+fn options_with_macros(macros: &[(&str, &str)]) -> RenderOptions {
+    RenderOptions {
+        math: MathOptions {
+            macros: macros
+                .iter()
+                .map(|(name, body)| (name.to_string(), body.to_string()))
+                .collect::<HashMap<_, _>>(),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_macros_from_options() {
+    let options =
+        options_with_macros(&[("\\R", "\\mathbb{R}"), ("norm", "\\left| #1 \\right|")]);
+
+    let expanded = render_markdown_with_options(r"Let $x \in \R$ and $\norm{v}$.", &options);
+    let direct = render_markdown(r"Let $x \in \mathbb{R}$ and $\left| v \right|$.");
+
+    assert!(!expanded.contains("math-error"), "got: {}", expanded);
+    assert_eq!(
+        expanded, direct,
+        "Expanded macros should match hand-written LaTeX"
+    );
+}
+
+#[test]
+fn test_newcommand_in_document() {
+    let input = r#"
+$$
+\newcommand{\R}{\mathbb{R}}
+\newcommand{\pair}[2]{\langle #1, #2 \rangle}
+$$
+
+Then $f: \R \to \R$ and $\pair{a}{b}$.
+"#;
+    let html = render_markdown(input);
+
+    assert!(!html.contains("math-error"), "got: {}", html);
+    // 只包含定义的公式不输出内容
+    assert_eq!(html.matches("<math").count(), 2, "got: {}", html);
+
+    let direct =
+        render_markdown(r"Then $f: \mathbb{R} \to \mathbb{R}$ and $\langle a, b \rangle$.");
+    assert!(html.contains(direct.trim()), "got: {}", html);
+}
+
+#[test]
+fn test_def_with_arguments() {
+    let html = render_markdown(r"$\def\sq#1{{#1}^2} \sq{x} + \sq y$");
+    let direct = render_markdown(r"${x}^2 + {y}^2$");
+
+    assert!(!html.contains("math-error"), "got: {}", html);
+    assert_eq!(html, direct);
+}
+
+#[test]
+fn test_nested_macros() {
+    let options = options_with_macros(&[("\\R", "\\mathbb{R}"), ("\\Rn", "\\R^n")]);
+    let html = render_markdown_with_options(r"$v \in \Rn$", &options);
+
+    assert_eq!(html, render_markdown(r"$v \in \mathbb{R}^n$"));
+}
+
+#[test]
+fn test_recursive_macro_guard() {
+    let html = render_markdown(r"$\def\loop{x\loop} \loop$");
+    assert!(
+        html.contains("math-error"),
+        "Infinite recursion must be reported: {}",
+        html
+    );
+
+    // 指数增长的展开也会被截断
+    let html = render_markdown(r"$\def\a{\b\b} \def\b{\c\c} \def\c{\d\d} \def\d{xx} \a$");
+    assert!(
+        !html.contains("math-error"),
+        "Small expansions are fine: {}",
+        html
+    );
+    let options = options_with_macros(&[("\\x", "\\x\\x")]);
+    let html = render_markdown_with_options(r"$\x$", &options);
+    assert!(html.contains("math-error"));
+}
+
+#[test]
+fn test_macros_from_json_options() {
+    let options =
+        RenderOptions::from_json(r#"{"math": {"macros": {"\\R": "\\mathbb{R}"}}}"#).unwrap();
+    let html = render_markdown_with_options(r"$\R$", &options);
+    assert_eq!(html, render_markdown(r"$\mathbb{R}$"));
+}
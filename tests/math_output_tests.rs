@@ -0,0 +1,119 @@
+use awsm_markdown_renderer::{
+    EquationNumbers, MathOptions, MathOutput, RenderOptions, render_markdown,
+    render_markdown_with_options,
+};
+
+fn output(output: MathOutput) -> RenderOptions {
+    RenderOptions {
+        math: MathOptions {
+            output,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+const INPUT: &str = "Inline $a > b$ and\n\n$$\n\\frac{1}{2}\n$$\n\n```math\nx^2\n```";
+
+#[test]
+fn test_mathml_output_is_default() {
+    assert_eq!(
+        render_markdown(INPUT),
+        render_markdown_with_options(INPUT, &output(MathOutput::Mathml))
+    );
+}
+
+#[test]
+fn test_mathml_with_tex_annotation() {
+    let html = render_markdown_with_options(INPUT, &output(MathOutput::MathmlWithTexAnnotation));
+
+    assert_eq!(
+        html.matches("<semantics><mrow>").count(),
+        3,
+        "got: {}",
+        html
+    );
+    assert!(html.contains(
+        r#"<annotation encoding="application/x-tex">a &gt; b</annotation></semantics></math>"#
+    ));
+    assert!(html.contains(r#"<annotation encoding="application/x-tex">\frac{1}{2}</annotation>"#));
+    assert!(html.contains(r#"<annotation encoding="application/x-tex">x^2</annotation>"#));
+    assert!(html.contains(r#"display="block""#));
+}
+
+#[test]
+fn test_passthrough_output() {
+    let html = render_markdown_with_options(INPUT, &output(MathOutput::Passthrough));
+
+    assert!(!html.contains("<math"), "got: {}", html);
+    assert!(html.contains(r#"<span class="math inline" data-tex="a &gt; b">a &gt; b</span>"#));
+    assert!(
+        html.contains(r#"<span class="math display" data-tex="\frac{1}{2}">\frac{1}{2}</span>"#)
+    );
+    assert!(html.contains(r#"<span class="math display" data-tex="x^2">x^2</span>"#));
+}
+
+#[test]
+fn test_passthrough_escapes_tex() {
+    let html =
+        render_markdown_with_options(r#"$\text{"x"} > y$"#, &output(MathOutput::Passthrough));
+    assert!(!html.contains(r#""x""#), "got: {}", html);
+    assert!(html.contains(r#"data-tex="\text{&quot;x&quot;} &gt; y""#));
+}
+
+#[test]
+fn test_output_modes_apply_macros_and_numbering() {
+    let mut options = output(MathOutput::Passthrough);
+    options
+        .math
+        .macros
+        .insert("\\R".into(), "\\mathbb{R}".into());
+    options.math.equation_numbers = EquationNumbers::All;
+
+    let html = render_markdown_with_options(
+        "$$\nx \\in \\R \\label{eq:x}\n$$\n\nSee \\eqref{eq:x}.",
+        &options,
+    );
+
+    assert!(
+        html.contains(r#"data-tex="x \in \mathbb{R}""#),
+        "got: {}",
+        html
+    );
+    assert!(html.contains(r#"<span class="math-equation-number">(1)</span>"#));
+    assert!(html.contains(r##"<a class="math-ref" href="#eq-eq:x">(1)</a>"##));
+}
+
+#[test]
+fn test_annotation_keeps_reference_source() {
+    let mut options = output(MathOutput::MathmlWithTexAnnotation);
+    options.math.equation_numbers = EquationNumbers::All;
+
+    let html =
+        render_markdown_with_options("$$\na \\label{a}\n$$\n\n$$\nb = \\eqref{a}\n$$", &options);
+
+    assert!(
+        html.contains(r##"<mtext><a class="math-ref" href="#eq-a">(1)</a></mtext>"##),
+        "got: {}",
+        html
+    );
+    assert!(
+        html.contains(r#"<annotation encoding="application/x-tex">b = \eqref{a}</annotation>"#)
+    );
+}
+
+#[test]
+fn test_output_from_json_options() {
+    for (json, expected) in [
+        ("mathml", MathOutput::Mathml),
+        (
+            "mathml-with-tex-annotation",
+            MathOutput::MathmlWithTexAnnotation,
+        ),
+        ("passthrough", MathOutput::Passthrough),
+    ] {
+        let options =
+            RenderOptions::from_json(&format!(r#"{{"math": {{"output": "{}"}}}}"#, json)).unwrap();
+        assert_eq!(options.math.output, expected);
+    }
+}
@@ -0,0 +1,182 @@
+use awsm_markdown_renderer::{
+    MathOptions, MathParser, RenderOptions, render_markdown, render_markdown_with_options,
+};
+
+fn native_options() -> RenderOptions {
+    RenderOptions {
+        math: MathOptions {
+            parser: MathParser::Native,
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+// 常用的 MathML 片段
+const H2: &str = r#"<msub><mi mathvariant="normal">H</mi><mn>2</mn></msub>"#;
+const O: &str = r#"<mi mathvariant="normal">O</mi>"#;
+const O2: &str = r#"<msub><mi mathvariant="normal">O</mi><mn>2</mn></msub>"#;
+
+/// mhchem 用例：(`\ce{}` 内容, 输出中必须依次出现的 MathML 片段)
+const FIXTURES: &[(&str, &[&str])] = &[
+    // 化学式与下标
+    ("H2O", &[H2, O]),
+    (
+        "(NH4)2SO4",
+        &[
+            "<mo>(</mo>",
+            r#"<msub><mi mathvariant="normal">H</mi><mn>4</mn></msub>"#,
+            "<msub><mo>)</mo><mn>2</mn></msub>",
+            r#"<msub><mi mathvariant="normal">O</mi><mn>4</mn></msub>"#,
+        ],
+    ),
+    // 电荷
+    (
+        "Na+",
+        &[
+            r#"<msup><mrow><mi mathvariant="normal">N</mi><mi mathvariant="normal">a</mi></mrow><mo>+</mo></msup>"#,
+        ],
+    ),
+    (
+        "NO3-",
+        &[r#"<msup><msub><mi mathvariant="normal">O</mi><mn>3</mn></msub><mo>-</mo></msup>"#],
+    ),
+    (
+        "SO4^2-",
+        &[
+            r#"<msup><msub><mi mathvariant="normal">O</mi><mn>4</mn></msub><mrow><mn>2</mn><mo>-</mo></mrow></msup>"#,
+        ],
+    ),
+    (
+        "Fe^{3+}",
+        &["<msup><mrow>", "<mrow><mn>3</mn><mo>+</mo></mrow></msup>"],
+    ),
+    (
+        "[Cu(NH3)4]^2+",
+        &[
+            "<mo>[</mo>",
+            "<msub><mo>)</mo><mn>4</mn></msub>",
+            "<msup><mo>]</mo><mrow><mn>2</mn><mo>+</mo></mrow></msup>",
+        ],
+    ),
+    // 系数、分数系数与水合物
+    (
+        "2H2 + O2 -> 2H2O",
+        &[
+            "<mn>2</mn>",
+            H2,
+            "<mo>+</mo>",
+            O2,
+            "<mo>⟶</mo>",
+            "<mn>2</mn>",
+            H2,
+            O,
+        ],
+    ),
+    ("1/2O2", &["<mfrac><mn>1</mn><mn>2</mn></mfrac>", O2]),
+    ("CuSO4*5H2O", &["<mo>·</mo>", "<mn>5</mn>", H2, O]),
+    // 同位素
+    (
+        "^{14}_{6}C",
+        &[
+            r#"<msup><msub><mrow></mrow><mn>6</mn></msub><mn>14</mn></msup><mi mathvariant="normal">C</mi>"#,
+        ],
+    ),
+    // 状态、气体与沉淀
+    (
+        "NaCl(aq)",
+        &[r#"<mo>(</mo><mi mathvariant="normal">a</mi><mi mathvariant="normal">q</mi><mo>)</mo>"#],
+    ),
+    ("H2O(l)", &[H2, O, r#"<mi mathvariant="normal">l</mi>"#]),
+    ("CO2 ^", &[O2, "<mo>↑</mo>"]),
+    ("Ag+ + Cl- -> AgCl v", &["<mo>⟶</mo>", "<mo>↓</mo>"]),
+    // 箭头
+    ("A <=> B", &["<mo>⇌</mo>"]),
+    ("A <-> B", &["<mo>⟷</mo>"]),
+    ("A <- B", &["<mo>⟵</mo>"]),
+    ("A <=>> B", &["<mo>⇌</mo>"]),
+    (
+        r"CaCO3 ->[\Delta] CaO + CO2",
+        &[r#"<mover><mo>⟶</mo><mi mathvariant="normal">Δ</mi></mover>"#],
+    ),
+    (
+        "A ->[H2O][heat] B",
+        &[
+            "<munder><mover><mo>⟶</mo>",
+            H2,
+            r#"<mi mathvariant="normal">h</mi>"#,
+        ],
+    ),
+    // 化学键
+    ("CH3-CH3", &["<mo>-</mo>"]),
+    ("CH2=CH2", &["<mo>=</mo>"]),
+    ("HC#CH", &["<mo>≡</mo>"]),
+    // 嵌入的数学
+    ("$x$ H2O", &["<mi>x</mi>", H2]),
+];
+
+#[test]
+fn test_mhchem_fixtures() {
+    let options = native_options();
+
+    for (ce, fragments) in FIXTURES {
+        let html = render_markdown_with_options(&format!("$\\ce{{{}}}$", ce), &options);
+
+        assert_eq!(
+            html.matches("<math").count(),
+            1,
+            "\\ce{{{}}} got: {}",
+            ce,
+            html
+        );
+        assert!(
+            !html.contains("PARSE ERROR") && !html.contains("math-error"),
+            "\\ce{{{}}} failed to render: {}",
+            ce,
+            html
+        );
+
+        let mut pos = 0;
+        for fragment in *fragments {
+            match html[pos..].find(fragment) {
+                Some(offset) => pos += offset + fragment.len(),
+                None => panic!(
+                    "\\ce{{{}}} should contain {:?} (in order), got: {}",
+                    ce, fragment, html
+                ),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_mhchem_in_display_and_regex_mode() {
+    let html = render_markdown("$$\n\\ce{2H2 + O2 -> 2H2O}\n$$");
+    assert!(html.contains(r#"display="block""#), "got: {}", html);
+    assert!(html.contains("<mo>⟶</mo>"));
+    assert!(!html.contains("PARSE ERROR"));
+
+    let html = render_markdown(r"Water is $\ce{H2O}$.");
+    assert!(html.contains(H2), "got: {}", html);
+}
+
+#[test]
+fn test_mhchem_mixed_with_math_and_macros() {
+    let mut options = native_options();
+    options
+        .math
+        .macros
+        .insert("water".into(), r"\ce{H2O}".into());
+
+    let html = render_markdown_with_options(r"$K = \frac{[\ce{H+}]}{\water}$", &options);
+    assert!(html.contains("<mfrac>"), "got: {}", html);
+    assert!(html.contains(H2));
+    assert!(!html.contains("PARSE ERROR"));
+}
+
+#[test]
+fn test_mhchem_leaves_other_commands_alone() {
+    let html = render_markdown_with_options(r"$\cdot \ce{O2}$", &native_options());
+    assert!(html.contains("<mo>·</mo>"), "got: {}", html);
+    assert!(html.contains(O2));
+}
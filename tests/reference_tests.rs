@@ -0,0 +1,122 @@
+use awsm_markdown_renderer::{
+    Reference, ReferenceOptions, RenderOptions, render_markdown_with_metadata,
+    render_markdown_with_options,
+};
+
+fn reference_options() -> RenderOptions {
+    RenderOptions {
+        references: ReferenceOptions {
+            user_url: Some("https://example.com/users/{user}".to_string()),
+            issue_url: Some("https://example.com/{repo}/issues/{number}".to_string()),
+            commit_url: Some("https://example.com/{repo}/commit/{sha}".to_string()),
+            repository: "acme/app".to_string(),
+        },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_reference_links() {
+    let html = render_markdown_with_options(
+        "Thanks @alice! Fixes #12 and acme/lib#7 in a5c3785ed8d6a35868bc169f07e40e889087fd2e.",
+        &reference_options(),
+    );
+    assert_eq!(
+        html,
+        concat!(
+            "<p>Thanks <a href=\"https://example.com/users/alice\" class=\"user-mention\">@alice</a>! ",
+            "Fixes <a href=\"https://example.com/acme/app/issues/12\" class=\"issue-link\">#12</a> ",
+            "and <a href=\"https://example.com/acme/lib/issues/7\" class=\"issue-link\">acme/lib#7</a> ",
+            "in <a href=\"https://example.com/acme/app/commit/a5c3785ed8d6a35868bc169f07e40e889087fd2e\" class=\"commit-link\"><code>a5c3785</code></a>.</p>\n",
+        )
+    );
+}
+
+#[test]
+fn test_each_reference_kind_needs_its_template() {
+    let options = RenderOptions {
+        references: ReferenceOptions {
+            issue_url: Some("/issues/{number}".to_string()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let html = render_markdown_with_options("@alice #3 a5c3785", &options);
+    assert_eq!(
+        html,
+        "<p>@alice <a href=\"/issues/3\" class=\"issue-link\">#3</a> a5c3785</p>\n"
+    );
+}
+
+#[test]
+fn test_reference_boundaries() {
+    let html = render_markdown_with_options(
+        "x@y C#1 issue#2 #12ab @-bad @a--b @x_y 1234567 defaced (#3) @carol.",
+        &reference_options(),
+    );
+    assert_eq!(
+        html,
+        concat!(
+            "<p>x@y C#1 issue#2 #12ab @-bad @a--b @x_y 1234567 defaced ",
+            "(<a href=\"https://example.com/acme/app/issues/3\" class=\"issue-link\">#3</a>) ",
+            "<a href=\"https://example.com/users/carol\" class=\"user-mention\">@carol</a>.</p>\n",
+        )
+    );
+}
+
+#[test]
+fn test_references_are_collected() {
+    let result = render_markdown_with_metadata(
+        "@alice see #12, acme/lib#7 and a5c3785. Ping @alice about #12 again.\n\n`@ignored`",
+        &reference_options(),
+    );
+    assert_eq!(
+        result.references,
+        vec![
+            Reference::User {
+                name: "alice".to_string()
+            },
+            Reference::Issue {
+                repository: Some("acme/app".to_string()),
+                number: 12
+            },
+            Reference::Issue {
+                repository: Some("acme/lib".to_string()),
+                number: 7
+            },
+            Reference::Commit {
+                sha: "a5c3785".to_string()
+            },
+        ]
+    );
+
+    let json = serde_json::to_value(&result).unwrap();
+    assert_eq!(
+        json["references"][0],
+        serde_json::json!({ "type": "user", "name": "alice" })
+    );
+    assert_eq!(
+        json["references"][1],
+        serde_json::json!({ "type": "issue", "repository": "acme/app", "number": 12 })
+    );
+}
+
+#[test]
+fn test_reference_options_from_json() {
+    let options = RenderOptions::from_json(
+        r#"{"references": {"issueUrl": "/t/{number}", "repository": ""}}"#,
+    )
+    .unwrap();
+    let result = render_markdown_with_metadata("See #4", &options);
+    assert_eq!(
+        result.html,
+        "<p>See <a href=\"/t/4\" class=\"issue-link\">#4</a></p>\n"
+    );
+    assert_eq!(
+        result.references,
+        vec![Reference::Issue {
+            repository: None,
+            number: 4
+        }]
+    );
+}
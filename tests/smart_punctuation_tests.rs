@@ -0,0 +1,74 @@
+use awsm_markdown_renderer::{RenderOptions, render_markdown_with_options};
+
+mod common;
+
+fn smart_options(locale: &str) -> RenderOptions {
+    RenderOptions {
+        smart_punctuation: true,
+        locale: locale.to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_english_smart_punctuation() {
+    let html = render_markdown_with_options(
+        r#"He said "it's 'nice'" -- ok --- fine..."#,
+        &smart_options(""),
+    );
+    assert_eq!(html, "<p>He said “it’s ‘nice’” – ok — fine…</p>\n");
+}
+
+#[test]
+fn test_locale_quotes() {
+    let cases = [
+        ("en", "“it’s ‘nice’”"),
+        ("de", "„it’s ‚nice‘“"),
+        ("de-CH", "„it’s ‚nice‘“"),
+        ("fr", "«\u{202F}it’s ‹\u{202F}nice\u{202F}›\u{202F}»"),
+        ("ja", "「it’s 『nice』」"),
+        ("zh", "「it’s 『nice』」"),
+        ("zh_TW", "「it’s 『nice』」"),
+        ("xx", "“it’s ‘nice’”"),
+    ];
+    for (locale, expected) in cases {
+        let html = render_markdown_with_options(r#""it's 'nice'""#, &smart_options(locale));
+        assert_eq!(html, format!("<p>{}</p>\n", expected), "locale {}", locale);
+    }
+}
+
+#[test]
+fn test_literal_curly_quotes_are_kept() {
+    let html = render_markdown_with_options(r#"“lit” and "smart""#, &smart_options("de"));
+    assert_eq!(html, "<p>“lit” and „smart“</p>\n");
+}
+
+#[test]
+fn test_smart_punctuation_skips_code_and_math() {
+    for parser in common::PARSERS {
+        let mut options = smart_options("fr");
+        options.math.parser = parser;
+
+        let html = render_markdown_with_options(
+            "`\"c\" -- x...` and $f'(x)$ or $1,...,n$\n\n```\n\"x\" 'y' -- z...\n```\n\n$$\ng'(x) = \"a\"\n$$",
+            &options,
+        );
+        assert!(
+            html.contains("<code>\"c\" -- x...</code>"),
+            "{:?} got: {}",
+            parser,
+            html
+        );
+        assert!(html.contains("&quot;x&quot; &#39;y&#39; -- z..."));
+        // 导数的撇号仍然是 LaTeX 的 `'`（渲染为 ′），而不是引号
+        assert_eq!(html.matches("<msup><mi>f</mi><mo>′</mo></msup>").count(), 1);
+        assert!(html.contains("<msup><mi>g</mi><mo>′</mo></msup>"));
+        assert!(html.contains("<mo>.</mo><mo>.</mo><mo>.</mo>"));
+        assert!(
+            !html.contains('«') && !html.contains('…'),
+            "{:?} got: {}",
+            parser,
+            html
+        );
+    }
+}
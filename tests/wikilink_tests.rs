@@ -0,0 +1,115 @@
+use awsm_markdown_renderer::{
+    RenderOptions, WikiLinkTarget, render_markdown_with_metadata, render_markdown_with_options,
+    render_markdown_with_resolver,
+};
+
+fn wikilink_options() -> RenderOptions {
+    RenderOptions {
+        wikilinks: true,
+        ..Default::default()
+    }
+}
+
+// 已有的页面：Home 与 Getting Started
+fn resolve(page: &str) -> WikiLinkTarget {
+    let slug = page.to_lowercase().replace(' ', "-");
+    WikiLinkTarget {
+        exists: matches!(page, "Home" | "Getting Started" | "logo.png"),
+        href: format!("/wiki/{}", slug),
+    }
+}
+
+#[test]
+fn test_wikilinks_without_resolver_link_to_page_name() {
+    let html = render_markdown_with_options(
+        "See [[Home]] and [[Getting Started|the guide]].",
+        &wikilink_options(),
+    );
+    assert_eq!(
+        html,
+        "<p>See <a href=\"Home\">Home</a> and <a href=\"Getting%20Started\">the guide</a>.</p>\n"
+    );
+
+    let result = render_markdown_with_metadata("[[Anything]]", &wikilink_options());
+    assert!(result.missing_wikilinks.is_empty());
+}
+
+#[test]
+fn test_wikilink_resolver() {
+    let result = render_markdown_with_resolver(
+        "[[Home]], [[Getting Started#Install|install]] and [[#Usage]]",
+        &wikilink_options(),
+        &resolve,
+    );
+    assert_eq!(
+        result.html,
+        concat!(
+            "<p><a href=\"/wiki/home\">Home</a>, ",
+            "<a href=\"/wiki/getting-started#Install\">install</a> and ",
+            "<a href=\"#Usage\">#Usage</a></p>\n",
+        )
+    );
+    assert!(result.missing_wikilinks.is_empty());
+}
+
+#[test]
+fn test_missing_wikilinks() {
+    let result = render_markdown_with_resolver(
+        "[[Roadmap]] and [[Draft Notes|notes]], again [[Roadmap]] and [[Home]]",
+        &wikilink_options(),
+        &resolve,
+    );
+    assert_eq!(
+        result.html,
+        concat!(
+            "<p><a href=\"/wiki/roadmap\" class=\"wikilink-missing\">Roadmap</a> and ",
+            "<a href=\"/wiki/draft-notes\" class=\"wikilink-missing\">notes</a>, ",
+            "again <a href=\"/wiki/roadmap\" class=\"wikilink-missing\">Roadmap</a> and ",
+            "<a href=\"/wiki/home\">Home</a></p>\n",
+        )
+    );
+    assert_eq!(result.missing_wikilinks, ["Roadmap", "Draft Notes"]);
+
+    let json = serde_json::to_value(&result).unwrap();
+    assert_eq!(
+        json["missingWikilinks"],
+        serde_json::json!(["Roadmap", "Draft Notes"])
+    );
+}
+
+#[test]
+fn test_wikilink_embeds() {
+    let result = render_markdown_with_resolver(
+        "![[logo.png]] ![[missing.png|A diagram]]",
+        &wikilink_options(),
+        &resolve,
+    );
+    assert_eq!(
+        result.html,
+        "<p><img src=\"/wiki/logo.png\" alt=\"logo.png\" /> <img src=\"/wiki/missing.png\" alt=\"A diagram\" /></p>\n"
+    );
+    assert_eq!(result.missing_wikilinks, ["missing.png"]);
+}
+
+#[test]
+fn test_wikilinks_in_code_and_with_text_passes() {
+    let result = render_markdown_with_resolver(
+        "`[[Roadmap]]` [[Roadmap|@alice https://example.com]]\n\n```\n[[Roadmap]]\n```",
+        &wikilink_options(),
+        &resolve,
+    );
+    assert!(
+        result.html.contains("<code>[[Roadmap]]</code>"),
+        "got: {}",
+        result.html
+    );
+    // 链接文字中的文本不会再变成自动链接
+    assert!(
+        result.html.contains(
+            "<a href=\"/wiki/roadmap\" class=\"wikilink-missing\">@alice https://example.com</a>"
+        ),
+        "got: {}",
+        result.html
+    );
+    assert_eq!(result.missing_wikilinks, ["Roadmap"]);
+}
@@ -1,7 +1,224 @@
 // wrapper.d.ts
+/**
+ * Options accepted by `render_markdown_with_options`.
+ * Every field is optional; omitted fields keep the default `render_markdown` behavior.
+ */
+export interface ContainerOptions {
+    /** HTML element to emit (default `"div"`). */
+    element?: string;
+    /** `class` attribute (default `"custom-container <name>"`). */
+    class?: string;
+    /** Title used when the opening line has none; omitted means no title row. */
+    title?: string;
+}
+
+export interface ReferenceOptions {
+    /** Link for `@user`; `{user}` is replaced, e.g. `"https://github.com/{user}"`. */
+    userUrl?: string;
+    /**
+     * Link for `#123` and `org/repo#123`; `{repo}` and `{number}` are replaced,
+     * e.g. `"https://github.com/{repo}/issues/{number}"`.
+     */
+    issueUrl?: string;
+    /** Link for 7-40 character hex SHAs; `{repo}` and `{sha}` (full SHA) are replaced. */
+    commitUrl?: string;
+    /** Current repository (`org/repo`), used as `{repo}` for `#123` and SHAs. */
+    repository?: string;
+}
+
+export interface EmojiOptions {
+    /** Replace `:tada:`, `:+1:` and other GitHub (gemoji) shortcodes with emoji. */
+    enabled?: boolean;
+    /**
+     * Emit `<img class="emoji">` instead of Unicode; `{name}` (shortcode) and `{unicode}`
+     * (hex codepoints joined by `-`, e.g. `1f389`) are replaced.
+     */
+    imageUrl?: string;
+}
+
+export interface RenderOptions {
+    /**
+     * Wrap each code block in `<div class="awsm-code-block" data-lang="..." data-code="...">`
+     * with a header containing the language label and an optional `title="..."` from the info string.
+     * `data-code` holds the unhighlighted source for copy buttons.
+     */
+    codeBlockContainer?: boolean;
+    /**
+     * Add GitHub-style `id` slugs to headings; duplicates get `-1`, `-2`, ... suffixes.
+     * Math contributes its LaTeX source, e.g. `# Energy $E=mc^2$` gets `id="energy-emc2"`.
+     */
+    headingIds?: boolean;
+    /**
+     * Read a trailing `{#id .class key=value}` block on headings, e.g. `## Install {#install .tabbed}`.
+     * Explicit ids take precedence over `headingIds` slugs, which skip ids already taken.
+     */
+    headingAttributes?: boolean;
+    /** Definition lists: a term line followed by `: definition` lines. */
+    definitionLists?: boolean;
+    /** Superscript with `^sup^`. */
+    superscript?: boolean;
+    /**
+     * Subscript with `~sub~`. Replaces GFM's single-tilde strikethrough; `~~strike~~` is unaffected.
+     */
+    subscript?: boolean;
+    /** Smart punctuation: curly quotes, `--` / `---` dashes and `...` ellipses. Code and math are untouched. */
+    smartPunctuation?: boolean;
+    /**
+     * Locale for smart quotes: `"en"` (default, “…” ‘…’), `"de"` („…“ ‚…‘), `"fr"` (« … » ‹ … ›, with narrow no-break spaces inside),
+     * `"ja"` / `"zh"` (「…」 『…』). Region subtags such as `"de-CH"` are ignored.
+     */
+    locale?: string;
+    /**
+     * PHP Markdown Extra abbreviations: `*[HTML]: Hyper Text Markup Language` lines are removed and
+     * whole-word `HTML` in text becomes `<abbr title="...">`. Code, math and links are untouched.
+     */
+    abbreviations?: boolean;
+    /**
+     * Obsidian-style wiki links: `[[Page]]`, `[[Page|label]]` and `![[image.png]]` embeds.
+     * Use `render_markdown_with_resolver` to map page names to hrefs and flag missing pages.
+     */
+    wikilinks?: boolean;
+    /**
+     * Extra `::: name` containers, keyed by name. Registered names override the built-in
+     * `tip`, `warning`, `danger` and `details` containers.
+     */
+    containers?: Record<string, ContainerOptions>;
+    /** Link `@user`, `#123`, `org/repo#123` and commit SHAs. Each kind is only linked when its template is set. */
+    references?: ReferenceOptions;
+    /** Emoji shortcodes such as `:tada:`; code, math and links are left alone. */
+    emoji?: EmojiOptions;
+    /** Math formula options. */
+    math?: MathOptions;
+}
+
+export interface MathOptions {
+    /**
+     * How formulas are found.
+     * - `"regex"` (default): scan text for `$...$` / `$$...$$`.
+     * - `"native"`: use pulldown-cmark's math parsing, so emphasis and escapes never split a formula.
+     */
+    parser?: "regex" | "native";
+    /**
+     * Native parser only (default `true`): an inline `$` must not touch an ASCII letter or digit
+     * on its outer side, so `a$x$b` and `$x$5` stay text.
+     */
+    githubBoundaries?: boolean;
+    /**
+     * How a single `$` delimits inline math. An escaped `\$` is always a literal dollar.
+     * - `"github"` (default): no whitespace just inside the `$`s, and no ASCII letter or digit just outside.
+     * - `"pandoc"`: no whitespace just inside, and the closing `$` must not be followed by a digit.
+     * - `"strict"`: single `$` is never math; only `$$...$$` and ```` ```math ```` blocks are.
+     */
+    dollarRule?: "github" | "pandoc" | "strict";
+    /** Formula delimiters, each independently toggleable. */
+    delimiters?: MathDelimiters;
+    /**
+     * LaTeX macros keyed by name (leading backslash optional), e.g. `{ "\\R": "\\mathbb{R}" }`.
+     * Arguments are written `#1`..`#9`. `\newcommand` / `\def` inside formulas also work.
+     */
+    macros?: Record<string, string>;
+    /**
+     * Display equation numbering (default `"none"`, only `\tag{...}` is shown).
+     * - `"all"`: number every display equation; `\nonumber` skips one.
+     * - `"labeled"`: number only equations with `\label{...}`.
+     * `\ref{label}` / `\eqref{label}` in text or math link to the equation.
+     */
+    equationNumbers?: "none" | "all" | "labeled";
+    /**
+     * Formula output format.
+     * - `"mathml"` (default): MathML.
+     * - `"mathml-with-tex-annotation"`: MathML with `<semantics>` and an `<annotation encoding="application/x-tex">`.
+     * - `"passthrough"`: escaped `<span class="math inline|display" data-tex="...">` for client-side KaTeX / MathJax.
+     */
+    output?: "mathml" | "mathml-with-tex-annotation" | "passthrough";
+    /**
+     * Accessible text added to each `<math>` as `alttext` and `aria-label` (default `"none"`).
+     * - `"tex"`: the LaTeX source.
+     * - `"speech"`: spoken English, e.g. `\frac{a}{b}` becomes "a over b".
+     */
+    altText?: "none" | "tex" | "speech";
+}
+
+export interface MathDelimiters {
+    /** `$...$` inline math (default `true`). */
+    dollars?: boolean;
+    /** `$$...$$` display math (default `true`). */
+    doubleDollars?: boolean;
+    /** `\(...\)` inline math, MathJax style (default `false`). */
+    parens?: boolean;
+    /** `\[...\]` display math, MathJax style (default `false`). */
+    brackets?: boolean;
+    /** `` $`...`$ `` inline math, GitLab style (default `false`). */
+    gitlab?: boolean;
+}
+
+/**
+ * Result of `render_markdown_with_metadata`.
+ */
+export interface RenderResult {
+    /** The rendered HTML, same as `render_markdown_with_options`. */
+    html: string;
+    /** Labels referenced by `\ref` / `\eqref` but never defined, in order of first use. */
+    unresolvedLabels: string[];
+    /** `@user`, `#123` / `org/repo#123` and commit references found in the text, deduplicated, in order of first use. */
+    references: Reference[];
+    /** Pages targeted by wiki links that the resolver reported as missing, deduplicated, in order of first use. */
+    missingWikilinks: string[];
+}
+
+/**
+ * Result of a wiki link resolver. A string is shorthand for `{ href, exists: true }`;
+ * `null` / `undefined` marks the page as missing. `href` defaults to the page name and `exists` to `true`.
+ */
+export type WikiLinkTarget = { href?: string; exists?: boolean } | string | null | undefined;
+
+export type Reference =
+    | { type: "user"; name: string }
+    | { type: "issue"; repository: string | null; number: number }
+    | { type: "commit"; sha: string };
+
 /**
  * Renders Markdown to HTML using the AWSM renderer.
  * @param markdown The markdown string to render.
  * @param wasmModule (Optional) For Cloudflare Workers, pass the imported .wasm module here.
  */
-export function render_markdown(markdown: string, wasmModule?: any): Promise<string>;
\ No newline at end of file
+export function render_markdown(markdown: string, wasmModule?: any): Promise<string>;
+
+/**
+ * Renders Markdown to HTML with the given options.
+ * @param markdown The markdown string to render.
+ * @param options Rendering options.
+ * @param wasmModule (Optional) For Cloudflare Workers, pass the imported .wasm module here.
+ */
+export function render_markdown_with_options(
+    markdown: string,
+    options?: RenderOptions,
+    wasmModule?: any
+): Promise<string>;
+
+/**
+ * Renders Markdown and returns the HTML together with metadata collected while rendering.
+ * @param markdown The markdown string to render.
+ * @param options Rendering options.
+ * @param wasmModule (Optional) For Cloudflare Workers, pass the imported .wasm module here.
+ */
+export function render_markdown_with_metadata(
+    markdown: string,
+    options?: RenderOptions,
+    wasmModule?: any
+): Promise<RenderResult>;
+
+/**
+ * Like `render_markdown_with_metadata`, but wiki link page names (the part before `#`) are passed to
+ * `resolver`. Links to missing pages get `class="wikilink-missing"` and are listed in `missingWikilinks`.
+ * @param markdown The markdown string to render.
+ * @param options Rendering options; set `wikilinks: true`.
+ * @param resolver Maps a page name to its href and whether the page exists.
+ * @param wasmModule (Optional) For Cloudflare Workers, pass the imported .wasm module here.
+ */
+export function render_markdown_with_resolver(
+    markdown: string,
+    options: RenderOptions | undefined,
+    resolver: (page: string) => WikiLinkTarget,
+    wasmModule?: any
+): Promise<RenderResult>;
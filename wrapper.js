@@ -3,13 +3,8 @@ import init, * as wasmExports from "./awsm_markdown_renderer.js";
 
 let initPromise = null;
 
-/**
- * 通用的 Markdown 渲染函数
- * @param {string} markdown - Markdown 文本
- * @param {WebAssembly.Module | Request | Response | Promise} [wasmModule] - (可选) Cloudflare Workers 必须传入导入的 .wasm 模块
- */
-export async function render_markdown(markdown, wasmModule) {
-  // 单例模式：保证只初始化一次
+// 单例模式：保证只初始化一次
+function ensureInit(wasmModule) {
   if (!initPromise) {
     // 情况 A: Cloudflare Workers (或者手动挡)
     // 用户显式传了 wasmModule 进来
@@ -22,7 +17,82 @@ export async function render_markdown(markdown, wasmModule) {
       initPromise = init();
     }
   }
+  return initPromise;
+}
 
-  await initPromise;
+/**
+ * 通用的 Markdown 渲染函数
+ * @param {string} markdown - Markdown 文本
+ * @param {WebAssembly.Module | Request | Response | Promise} [wasmModule] - (可选) Cloudflare Workers 必须传入导入的 .wasm 模块
+ */
+export async function render_markdown(markdown, wasmModule) {
+  await ensureInit(wasmModule);
   return wasmExports.render_markdown(markdown);
 }
+
+/**
+ * 带选项的 Markdown 渲染函数
+ * @param {string} markdown - Markdown 文本
+ * @param {object} [options] - 渲染选项，详见 wrapper.d.ts 中的 RenderOptions
+ * @param {WebAssembly.Module | Request | Response | Promise} [wasmModule] - (可选) Cloudflare Workers 必须传入导入的 .wasm 模块
+ */
+export async function render_markdown_with_options(markdown, options, wasmModule) {
+  await ensureInit(wasmModule);
+  return wasmExports.render_markdown_with_options(
+    markdown,
+    JSON.stringify(options ?? {}),
+  );
+}
+
+/**
+ * 与 Rust 侧 `RenderResult` 的 JSON 结构一致（见 wrapper.d.ts）
+ * @typedef {{type: "user", name: string} | {type: "issue", repository: string | null, number: number} | {type: "commit", sha: string}} Reference
+ * @typedef {{html: string, unresolvedLabels: string[], references: Reference[], missingWikilinks: string[]}} RenderResult
+ */
+
+/**
+ * 渲染 Markdown 并返回元数据（未解析的公式引用、文本中的引用、不存在的 Wiki 页面），结构见 wrapper.d.ts 中的 RenderResult
+ * @param {string} markdown - Markdown 文本
+ * @param {object} [options] - 渲染选项，详见 wrapper.d.ts 中的 RenderOptions
+ * @param {WebAssembly.Module | Request | Response | Promise} [wasmModule] - (可选) Cloudflare Workers 必须传入导入的 .wasm 模块
+ * @returns {Promise<RenderResult>}
+ */
+export async function render_markdown_with_metadata(markdown, options, wasmModule) {
+  await ensureInit(wasmModule);
+  return JSON.parse(
+    wasmExports.render_markdown_with_metadata(
+      markdown,
+      JSON.stringify(options ?? {}),
+    ),
+  );
+}
+
+/**
+ * 渲染 Markdown 并返回元数据，Wiki 链接（`wikilinks` 选项）的页面名由回调解析
+ * @param {string} markdown - Markdown 文本
+ * @param {object} [options] - 渲染选项，详见 wrapper.d.ts 中的 RenderOptions
+ * @param {(page: string) => ({href?: string, exists?: boolean} | string | null | undefined)} resolver - 页面名解析回调
+ * @param {WebAssembly.Module | Request | Response | Promise} [wasmModule] - (可选) Cloudflare Workers 必须传入导入的 .wasm 模块
+ * @returns {Promise<RenderResult>}
+ */
+export async function render_markdown_with_resolver(markdown, options, resolver, wasmModule) {
+  await ensureInit(wasmModule);
+  // 补全默认值：字符串视为存在页面的链接，null / undefined 视为不存在的页面
+  const resolve = (page) => {
+    const target = resolver(page);
+    if (typeof target === "string") {
+      return { href: target, exists: true };
+    }
+    return {
+      href: target?.href ?? page,
+      exists: target == null ? false : (target.exists ?? true),
+    };
+  };
+  return JSON.parse(
+    wasmExports.render_markdown_with_resolver(
+      markdown,
+      JSON.stringify(options ?? {}),
+      resolve,
+    ),
+  );
+}